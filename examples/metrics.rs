@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use steckrs::{extension_point, simple_plugin, PluginManager};
+
+// Define an extension point for hooks that keep state between calls
+extension_point!(
+    MetricsExtension:
+    MetricsTrait;
+    /// Record how long a request took
+    fn record(&mut self, duration: Duration);
+    /// Summarize everything recorded so far
+    fn summary(&self) -> String;
+);
+
+// Implement a stateful hook
+/// Keeps track of the amount and the average duration of requests
+#[derive(Default)]
+struct AverageCollector {
+    count: u32,
+    total: Duration,
+}
+
+impl MetricsTrait for AverageCollector {
+    fn record(&mut self, duration: Duration) {
+        self.count += 1;
+        self.total += duration;
+    }
+
+    fn summary(&self) -> String {
+        if self.count == 0 {
+            return "no requests".to_string();
+        }
+        format!(
+            "{} requests, {:?} on average",
+            self.count,
+            self.total / self.count
+        )
+    }
+}
+
+/// Keeps track of the slowest request
+#[derive(Default)]
+struct MaxCollector {
+    max: Duration,
+}
+
+impl MetricsTrait for MaxCollector {
+    fn record(&mut self, duration: Duration) {
+        self.max = self.max.max(duration);
+    }
+
+    fn summary(&self) -> String {
+        format!("slowest request took {:?}", self.max)
+    }
+}
+
+simple_plugin!(
+    /// A Plugin collecting request metrics
+    MetricsPlugin,
+    "metrics_plugin",
+    "Collects request metrics",
+    hooks: [
+        (MetricsExtension, AverageCollector::default(), "average"),
+        (MetricsExtension, MaxCollector::default(), "max"),
+    ]
+);
+
+fn main() {
+    let mut plugin_manager = PluginManager::new();
+    plugin_manager
+        .load_plugin(Box::new(MetricsPlugin::new()))
+        .unwrap();
+    plugin_manager.enable_plugin(MetricsPlugin::ID).unwrap();
+
+    // Simulate some requests, the hooks keep their state in the registry
+    for millis in [12, 40, 7, 23] {
+        let duration = Duration::from_millis(millis);
+        plugin_manager
+            .for_each_hook_mut::<MetricsExtension>(|hook| hook.inner_mut().record(duration));
+    }
+
+    for (id, hook) in plugin_manager.get_enabled_hooks_by_ep::<MetricsExtension>() {
+        println!(
+            "{}: {}",
+            id.discriminator.as_deref().unwrap_or_default(),
+            hook.inner().summary()
+        );
+    }
+}
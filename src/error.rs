@@ -12,6 +12,9 @@
 //!
 //! - [`PluginError`]: Errors related to [Plugin](crate::Plugin) management
 //! - [`HookError`]: Errors related to [hook](crate::hook::Hook) registration and management
+//! - [`BulkError`]: The errors of an operation on many [Plugins](crate::Plugin) at once
+//! - [`IntegrityIssue`]: Inconsistencies found by
+//!   [`HookRegistry::validate_integrity`](crate::hook::HookRegistry::validate_integrity)
 //!
 //! ## Result Types
 //!
@@ -20,7 +23,8 @@
 //! - [`PluginResult<T>`]: Results from plugin operations
 //! - [`HookResult<T>`]: Results from hook operations
 
-use crate::PluginIDOwned;
+use crate::hook::{ExtensionPointID, HookID};
+use crate::{PluginID, PluginIDOwned};
 
 /// Result type for plugin operations
 pub type HookResult<T> = Result<T, HookError>;
@@ -32,7 +36,7 @@ pub type PluginResult<T> = Result<T, PluginError>;
 ///
 /// These errors can occur during plugin loading, unloading, enabling,
 /// disabling, or other plugin management operations.
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, thiserror::Error)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PluginError {
     /// A [Plugin](crate::Plugin) is already loaded
@@ -43,6 +47,62 @@ pub enum PluginError {
     #[error("Plugin not found: {0}")]
     NotFound(PluginIDOwned),
 
+    /// A group of [Plugins](crate::Plugin) was requested that is not defined, see
+    /// [`PluginManager::define_group`](crate::PluginManager::define_group)
+    #[error("Plugin group not found: {0}")]
+    GroupNotFound(String),
+
+    /// A [Plugin](crate::Plugin) loaded without error, but reported
+    /// [`PluginStatus::Failed`](crate::PluginStatus::Failed) afterwards
+    #[error("{plugin} was loaded, but failed: {reason}")]
+    LoadedButFailed {
+        /// The plugin that failed
+        plugin: PluginIDOwned,
+        /// Why the plugin failed, as reported by the plugin
+        reason: String,
+    },
+
+    /// A [Plugin](crate::Plugin) panicked while it was loaded with
+    /// [`PluginManager::load_plugin_catch`](crate::PluginManager::load_plugin_catch)
+    #[error("{plugin} panicked while loading: {message}")]
+    Panicked {
+        /// The plugin that panicked
+        plugin: PluginIDOwned,
+        /// The panic message, if it was a string
+        message: String,
+    },
+
+    /// A [Plugin](crate::Plugin) requires a version of a dependency that is not loaded, see
+    /// [`Plugin::dependency_requirements`](crate::Plugin::dependency_requirements)
+    #[error("{plugin} requires {dependency} {required}, but {found} is loaded")]
+    VersionConflict {
+        /// The plugin that has the requirement
+        plugin: PluginIDOwned,
+        /// The dependency that does not satisfy the requirement
+        dependency: PluginIDOwned,
+        /// The version requirement of the plugin
+        required: String,
+        /// The version of the loaded dependency
+        found: String,
+    },
+
+    /// The [`PluginManager`](crate::PluginManager) was locked with
+    /// [`PluginManager::lock`](crate::PluginManager::lock), so its plugins can not be changed
+    #[error("The plugin manager is locked")]
+    Locked,
+
+    /// The version of a [Plugin](crate::Plugin) does not satisfy the requirement of the host, see
+    /// `PluginManager::require_version` (only available with the `semver` feature)
+    #[error("{id} has version {found}, but {required} is required")]
+    IncompatibleVersion {
+        /// The plugin that was not loaded
+        id: PluginIDOwned,
+        /// The version of the plugin
+        found: String,
+        /// The version requirement of the host
+        required: String,
+    },
+
     /// Something went wrong when working with hooks
     #[error("Hook error: {0}")]
     HookError(#[from] HookError),
@@ -52,10 +112,233 @@ pub enum PluginError {
 ///
 /// These errors can occur during hook registration, deregistration,
 /// or other hook management operations.
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, thiserror::Error)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HookError {
     /// Indicates a hook with the same ID is already registered.
     #[error("Tried to register to a hook that already exists")]
     AlreadyRegistered,
+
+    /// Indicates that the [`ExtensionPoint`](crate::hook::ExtensionPoint) of a hook does not
+    /// accept new hooks, see
+    /// [`HookRegistry::close_extension_point`](crate::hook::HookRegistry::close_extension_point).
+    #[error("Tried to register a hook for a closed extension point")]
+    ExtensionPointClosed,
+
+    /// Indicates that a hook was rejected by
+    /// [`ExtensionPoint::validate_hook`](crate::hook::ExtensionPoint::validate_hook), with the
+    /// reason given by the extension point.
+    #[error("The hook does not satisfy its extension point: {0}")]
+    ValidationFailed(String),
+
+    /// Indicates that no hook with the given [`HookID`] is registered.
+    #[error("Tried to access a hook that is not registered")]
+    NotFound,
+
+    /// Indicates that the [`HookID`] of a hook belongs to a different
+    /// [`ExtensionPoint`](crate::hook::ExtensionPoint) than the hook itself.
+    #[error("The extension point of the hook ID does not match the hook")]
+    ExtensionPointMismatch,
+}
+
+/// An inconsistency in the internal state of a [`HookRegistry`](crate::hook::HookRegistry).
+///
+/// These are found by
+/// [`HookRegistry::validate_integrity`](crate::hook::HookRegistry::validate_integrity). A registry
+/// that is only changed through its own methods should never have any of them, so finding one
+/// points to a bug, or to two [extension points](crate::hook::ExtensionPoint) that can not be told
+/// apart.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum IntegrityIssue {
+    /// A hook is stored under a different extension point than the one it belongs to
+    #[error("{id:?} is stored under {stored_under:?}, but belongs to {actual:?}")]
+    MisfiledHook {
+        /// The ID of the misfiled hook
+        id: HookID,
+        /// The extension point the hook is stored under
+        stored_under: ExtensionPointID,
+        /// The extension point of the hook itself, or of its [`HookID`] if those differ
+        actual: ExtensionPointID,
+    },
+
+    /// The hooks of one extension point report different extension point names
+    #[error("{extension_point:?} has hooks with different names: {names:?}")]
+    ConflictingNames {
+        /// The extension point
+        extension_point: ExtensionPointID,
+        /// All names reported by its hooks, sorted
+        names: Vec<&'static str>,
+    },
+
+    /// Several distinct extension points have the same name
+    #[error("{count} extension points are named {name}")]
+    DuplicateName {
+        /// The shared name
+        name: &'static str,
+        /// How many extension points have that name
+        count: usize,
+    },
+}
+
+/// Error type for operations on many [Plugins](crate::Plugin) at once.
+///
+/// Bulk operations, like
+/// [`PluginManager::set_enabled_many`](crate::PluginManager::set_enabled_many), keep going when
+/// the operation fails for one plugin. Afterwards, they report all failures together in a
+/// [`BulkError`], with the plugin each failure belongs to.
+///
+/// Results that were collected per plugin, for example by calling
+/// [`PluginManager::load_plugin`](crate::PluginManager::load_plugin) in a loop, can be turned into
+/// the same form with [`BulkError::from_results`].
+///
+/// # Examples
+///
+/// ```
+/// use steckrs::{PluginManager, error::PluginError, simple_plugin};
+///
+/// simple_plugin!(
+///     ExamplePlugin,
+///     "example_plugin",
+///     "An example plugin",
+///     hooks: []
+/// );
+///
+/// let mut manager = PluginManager::new();
+/// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+///
+/// let err = manager
+///     .set_enabled_many(&[("example_plugin", true), ("gone_a", true), ("gone_b", false)])
+///     .unwrap_err();
+///
+/// assert_eq!(err.len(), 2);
+/// for (plugin, error) in &err {
+///     assert!(matches!(error, PluginError::NotFound(_)));
+///     assert!(plugin.id().starts_with("gone"));
+/// }
+/// assert_eq!(err.to_string(), "2 plugin operations failed, first: Plugin not found: gone_a");
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BulkError {
+    errors: Vec<(PluginIDOwned, PluginError)>,
+}
+
+impl BulkError {
+    /// Creates a new [`BulkError`] without any failures.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collects the failures from a list of results per plugin.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BulkError`] with all failures, if there is at least one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::error::{BulkError, PluginError};
+    ///
+    /// let results = vec![
+    ///     ("plugin_a", Ok(())),
+    ///     ("plugin_b", Err(PluginError::NotFound("plugin_b".into()))),
+    /// ];
+    /// let err = BulkError::from_results(results).unwrap_err();
+    /// assert_eq!(err.len(), 1);
+    ///
+    /// assert!(BulkError::from_results([("plugin_a", Ok(()))]).is_ok());
+    /// ```
+    pub fn from_results<T>(
+        results: impl IntoIterator<Item = (PluginID, PluginResult<T>)>,
+    ) -> Result<(), Self> {
+        let mut bulk = Self::new();
+        for (id, result) in results {
+            if let Err(e) = result {
+                bulk.push(id, e);
+            }
+        }
+        bulk.into_result()
+    }
+
+    /// Adds the failure of a single plugin.
+    pub fn push(&mut self, plugin: impl Into<PluginIDOwned>, error: PluginError) {
+        self.errors.push((plugin.into(), error));
+    }
+
+    /// Returns `true` if no failures were added.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the number of failures.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns all failures, in the order they happened.
+    #[must_use]
+    pub fn errors(&self) -> &[(PluginIDOwned, PluginError)] {
+        &self.errors
+    }
+
+    /// Returns an iterator over all failures, in the order they happened.
+    pub fn iter(&self) -> std::slice::Iter<'_, (PluginIDOwned, PluginError)> {
+        self.errors.iter()
+    }
+
+    /// Returns `Ok(())` if there are no failures, and `self` as error otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns `self` if at least one failure was added.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl std::fmt::Display for BulkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.errors.first() {
+            Some((_plugin, first)) => write!(
+                f,
+                "{} plugin operations failed, first: {first}",
+                self.errors.len()
+            ),
+            None => write!(f, "no plugin operations failed"),
+        }
+    }
+}
+
+impl std::error::Error for BulkError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.errors
+            .first()
+            .map(|(_plugin, e)| e as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl IntoIterator for BulkError {
+    type Item = (PluginIDOwned, PluginError);
+    type IntoIter = std::vec::IntoIter<(PluginIDOwned, PluginError)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a BulkError {
+    type Item = &'a (PluginIDOwned, PluginError);
+    type IntoIter = std::slice::Iter<'a, (PluginIDOwned, PluginError)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
 }
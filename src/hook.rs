@@ -57,11 +57,17 @@
 //! ```
 
 use std::any::Any;
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 
-use crate::error::{HookError, HookResult};
+use tracing::{debug, warn};
+
+use crate::error::{HookError, HookResult, IntegrityIssue};
 use crate::PluginID;
 
 /// Type identifier for extension points.
@@ -70,6 +76,10 @@ use crate::PluginID;
 /// in the hook registry.
 pub type ExtensionPointID = std::any::TypeId;
 
+/// The [shared](Hook#ownership) implementations of the hooks of the [`ExtensionPoint`] `E`, by
+/// [`HookID`], see [`PluginManager::snapshot_hooks`](crate::PluginManager::snapshot_hooks).
+pub type HookSnapshot<E> = Arc<[(HookID, Arc<<E as ExtensionPoint>::HookTrait>)]>;
+
 /// Unique identifier for a specific hook instance.
 ///
 /// A `HookID` consists of:
@@ -158,6 +168,119 @@ impl HookID {
             discriminator: discriminator.map(std::convert::Into::into),
         }
     }
+
+    /// Creates a new hook ID for the [`ExtensionPoint`] `E`.
+    ///
+    /// This is the same as [`HookID::new`] with `E::id()` as `extension_point_id`, which makes it
+    /// impossible to accidentally pass the ID of a different extension point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{ExtensionPoint, HookID}};
+    ///
+    /// extension_point!(
+    ///     Logger: LoggerTrait;
+    ///     fn log(&self, message: &str);
+    /// );
+    ///
+    /// let discriminator = String::from("file_logger");
+    /// let hook_id = HookID::for_ep::<Logger>("logging_plugin", Some(&discriminator));
+    ///
+    /// assert_eq!(
+    ///     hook_id,
+    ///     HookID::new("logging_plugin", Logger::id(), Some("file_logger"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn for_ep<E: ExtensionPoint>(plugin_id: PluginID, discriminator: Option<&str>) -> Self {
+        HookID {
+            plugin_id,
+            extension_point_id: E::id(),
+            discriminator: discriminator.map(std::borrow::ToOwned::to_owned),
+        }
+    }
+
+    /// Starts building a hook ID for the given plugin, see [`HookIDBuilder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{ExtensionPoint, HookID}};
+    ///
+    /// extension_point!(
+    ///     Logger: LoggerTrait;
+    ///     fn log(&self, message: &str);
+    /// );
+    ///
+    /// let hook_id = HookID::builder("logging_plugin")
+    ///     .extension_point::<Logger>()
+    ///     .discriminator("file_logger")
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     hook_id,
+    ///     HookID::new("logging_plugin", Logger::id(), Some("file_logger"))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn builder(plugin_id: PluginID) -> HookIDBuilder<()> {
+        HookIDBuilder {
+            plugin_id,
+            discriminator: None,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Builder for a [`HookID`], created with [`HookID::builder`].
+///
+/// The [`ExtensionPoint`] is given as a type parameter with
+/// [`extension_point`](Self::extension_point), and [`build`](Self::build) is only available once
+/// it was set. Forgetting the extension point is therefore a compile error:
+///
+/// ```compile_fail
+/// use steckrs::hook::HookID;
+///
+/// let hook_id = HookID::builder("logging_plugin").discriminator("file_logger").build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct HookIDBuilder<E> {
+    plugin_id: PluginID,
+    discriminator: Option<String>,
+    marker: PhantomData<fn() -> E>,
+}
+
+impl<E> HookIDBuilder<E> {
+    /// Sets the [`ExtensionPoint`] the hook implements.
+    #[must_use]
+    pub fn extension_point<E2: ExtensionPoint>(self) -> HookIDBuilder<E2> {
+        HookIDBuilder {
+            plugin_id: self.plugin_id,
+            discriminator: self.discriminator,
+            marker: PhantomData,
+        }
+    }
+
+    /// Sets the discriminator, to tell apart several hooks of one plugin for the same extension
+    /// point.
+    #[must_use]
+    pub fn discriminator(mut self, discriminator: impl Into<String>) -> Self {
+        self.discriminator = Some(discriminator.into());
+        self
+    }
+}
+
+impl<E: ExtensionPoint> HookIDBuilder<E> {
+    /// Builds the [`HookID`].
+    #[must_use]
+    pub fn build(self) -> HookID {
+        HookID {
+            plugin_id: self.plugin_id,
+            extension_point_id: E::id(),
+            discriminator: self.discriminator,
+        }
+    }
 }
 
 /// Defines an extension point where plugins can hook into the application.
@@ -207,6 +330,33 @@ pub trait ExtensionPoint: Eq + Ord + 'static {
     /// The trait that hooks implement for this extension point
     type HookTrait: ?Sized + Send + Sync + 'static;
 
+    /// Documentation for this extension point, like a URL to its specification or a short
+    /// description.
+    ///
+    /// This is metadata for tooling on the host side, for example to link each extension point to
+    /// its documentation. It is not used by steckrs itself. Defaults to [`None`].
+    ///
+    /// With [`extension_point!`](crate::extension_point), this can be set with `docs = "..."`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::ExtensionPoint};
+    ///
+    /// extension_point!(
+    ///     Logger: LoggerTrait, docs = "https://example.com/specs/logger";
+    ///     fn log(&self, message: &str);
+    /// );
+    ///
+    /// extension_point!(
+    ///     Undocumented: UndocumentedTrait;
+    /// );
+    ///
+    /// assert_eq!(Logger::DOCS, Some("https://example.com/specs/logger"));
+    /// assert_eq!(Undocumented::DOCS, None);
+    /// ```
+    const DOCS: Option<&'static str> = None;
+
     /// Returns a unique identifier for this extension point type.
     ///
     /// By default, this uses Rust's [`TypeId`](std::any::TypeId)
@@ -252,6 +402,167 @@ pub trait ExtensionPoint: Eq + Ord + 'static {
     fn name() -> &'static str {
         std::any::type_name::<Self>()
     }
+
+    /// Returns the built-in default implementation of this extension point, if it has one.
+    ///
+    /// The default is used by [`HookRegistry::get_any`] and
+    /// [`PluginManager::get_any_enabled`](crate::PluginManager::get_any_enabled) when no plugin
+    /// provides a hook for this extension point, so plugins override it just by registering a
+    /// hook. The default is never stored in the [`HookRegistry`] and therefore has no [`HookID`].
+    /// This function is called again every time the default is needed.
+    ///
+    /// The default implementation returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::hook::{ExtensionPoint, HookRegistry};
+    ///
+    /// pub trait ClockTrait: Send + Sync {
+    ///     fn now(&self) -> u64;
+    /// }
+    ///
+    /// #[derive(Debug, Ord, Eq, PartialOrd, PartialEq)]
+    /// struct Clock;
+    /// impl ExtensionPoint for Clock {
+    ///     type HookTrait = dyn ClockTrait;
+    ///
+    ///     fn default_hook() -> Option<Box<Self::HookTrait>> {
+    ///         Some(Box::new(ZeroClock))
+    ///     }
+    /// }
+    ///
+    /// struct ZeroClock;
+    /// impl ClockTrait for ZeroClock {
+    ///     fn now(&self) -> u64 {
+    ///         0
+    ///     }
+    /// }
+    ///
+    /// let registry = HookRegistry::new();
+    /// let clock = registry.get_any::<Clock>().unwrap();
+    /// assert!(clock.is_default());
+    /// assert_eq!(clock.inner().now(), 0);
+    /// ```
+    #[must_use]
+    fn default_hook() -> Option<Box<Self::HookTrait>> {
+        None
+    }
+
+    /// Checks that a hook satisfies the contract of this extension point.
+    ///
+    /// This is called for every hook of this extension point when it is registered. If it
+    /// returns an error, the hook is not registered, and registering fails with
+    /// [`HookError::ValidationFailed`] with the returned reason. This lets the author of an
+    /// extension point enforce rules that the type system can not express, when the hook is
+    /// registered instead of when it is called.
+    ///
+    /// The default implementation accepts every hook.
+    ///
+    /// # Errors
+    ///
+    /// Returns the reason why the hook was rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{error::HookError, hook::{ExtensionPoint, Hook, HookID, HookRegistry}};
+    ///
+    /// pub trait ValidatorTrait: Send + Sync {
+    ///     fn validate(&self, input: &str) -> bool;
+    /// }
+    ///
+    /// #[derive(Debug, Ord, Eq, PartialOrd, PartialEq)]
+    /// struct Validator;
+    /// impl ExtensionPoint for Validator {
+    ///     type HookTrait = dyn ValidatorTrait;
+    ///
+    ///     fn validate_hook(hook: &Self::HookTrait) -> Result<(), String> {
+    ///         // every validator has to reject the empty string
+    ///         if hook.validate("") {
+    ///             Err("accepts the empty string".to_string())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// struct NotEmpty;
+    /// impl ValidatorTrait for NotEmpty {
+    ///     fn validate(&self, input: &str) -> bool {
+    ///         !input.is_empty()
+    ///     }
+    /// }
+    ///
+    /// struct AcceptAll;
+    /// impl ValidatorTrait for AcceptAll {
+    ///     fn validate(&self, _input: &str) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// let id = HookID::for_ep::<Validator>("validation_plugin", Some("not_empty"));
+    /// registry.register(&id, Hook::<Validator>::new(Box::new(NotEmpty), "not_empty")).unwrap();
+    ///
+    /// let id = HookID::for_ep::<Validator>("validation_plugin", Some("accept_all"));
+    /// let result = registry.register(&id, Hook::<Validator>::new(Box::new(AcceptAll), "accept_all"));
+    /// assert!(matches!(result, Err(HookError::ValidationFailed(_))));
+    /// ```
+    fn validate_hook(hook: &Self::HookTrait) -> Result<(), String> {
+        let _ = hook;
+        Ok(())
+    }
+}
+
+/// A hook that can describe itself in more detail than its [name](Hook::name).
+///
+/// To make the hooks of an [`ExtensionPoint`] describable, add this trait as a supertrait of the
+/// [`HookTrait`](ExtensionPoint::HookTrait) and register the hooks with
+/// [`HookRegistry::register_described`]. The description can then be queried with
+/// [`HookRegistry::description`], even though the registry stores hooks type-erased.
+///
+/// # Examples
+///
+/// ```
+/// use steckrs::hook::{DescribableHook, ExtensionPoint, Hook, HookID, HookRegistry};
+///
+/// pub trait ValidatorTrait: DescribableHook + Send + Sync {
+///     fn validate(&self, input: &str) -> bool;
+/// }
+///
+/// #[derive(Debug, Ord, Eq, PartialOrd, PartialEq)]
+/// struct Validator;
+/// impl ExtensionPoint for Validator {
+///     type HookTrait = dyn ValidatorTrait;
+/// }
+///
+/// struct MaxLength(usize);
+/// impl ValidatorTrait for MaxLength {
+///     fn validate(&self, input: &str) -> bool {
+///         input.len() <= self.0
+///     }
+/// }
+/// impl DescribableHook for MaxLength {
+///     fn describe(&self) -> String {
+///         format!("accepts inputs of at most {} bytes", self.0)
+///     }
+/// }
+///
+/// let mut registry = HookRegistry::new();
+/// let id = HookID::for_ep::<Validator>("validation_plugin", None);
+/// registry
+///     .register_described(&id, Hook::<Validator>::new(Box::new(MaxLength(8)), "max_len"))
+///     .unwrap();
+///
+/// assert_eq!(
+///     registry.description(&id).as_deref(),
+///     Some("accepts inputs of at most 8 bytes")
+/// );
+/// ```
+pub trait DescribableHook {
+    /// Returns a human-readable description of this hook.
+    fn describe(&self) -> String;
 }
 
 /// A wrapper around a hook trait object for a specific extension point.
@@ -287,12 +598,77 @@ pub trait ExtensionPoint: Eq + Ord + 'static {
 /// // Use the hook
 /// hook.inner().log("Hello from hook!");
 /// ```
+///
+/// # Ownership
+///
+/// A [`Hook`] either owns its implementation, or shares it with other hooks:
+///
+/// - Hooks created with [`Hook::new`] own their implementation in a [`Box`]. This is the common
+///   case, and allows mutable access with [`inner_mut`](Hook::inner_mut).
+/// - Hooks created with [`Hook::new_shared`] hold their implementation in an [`Arc`]. The same
+///   [`Arc`] can be used for multiple hooks, even for different [Extension Points](ExtensionPoint)
+///   if the implementation implements the traits of all of them. The implementation lives as long
+///   as any hook (or other [`Arc`]) referring to it. Mutable access is only possible while the
+///   hook holds the only reference, see [`try_inner_mut`](Hook::try_inner_mut).
 #[derive(Debug)]
 pub struct Hook<E: ExtensionPoint> {
     /// The actual hook trait object
-    inner: Box<E::HookTrait>,
+    inner: HookInner<E::HookTrait>,
+    #[allow(clippy::struct_field_names)]
     hook_t: PhantomData<E::HookTrait>,
-    name: &'static str,
+    name: Cow<'static, str>,
+    priority: i32,
+}
+
+/// Either a registered [`Hook`], or the [default hook](ExtensionPoint::default_hook) of an
+/// [`ExtensionPoint`].
+///
+/// This is returned by lookups that fall back to the default hook when no hook is registered,
+/// like [`HookRegistry::get_any`]. Use [`inner`](Self::inner) to call the hook regardless of
+/// where it came from.
+#[derive(Debug)]
+pub enum HookOrDefault<'a, E: ExtensionPoint> {
+    /// A hook from the [`HookRegistry`]
+    Registered(&'a Hook<E>),
+    /// The [default hook](ExtensionPoint::default_hook) of the extension point, which is not in
+    /// the [`HookRegistry`]
+    Default(Box<E::HookTrait>),
+}
+
+impl<'a, E: ExtensionPoint> HookOrDefault<'a, E> {
+    /// Returns a reference to the implementation of the hook.
+    #[must_use]
+    pub fn inner(&self) -> &E::HookTrait {
+        match self {
+            Self::Registered(hook) => hook.inner(),
+            Self::Default(hook) => hook.as_ref(),
+        }
+    }
+
+    /// Returns `true` if this is the [default hook](ExtensionPoint::default_hook).
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        matches!(self, Self::Default(_))
+    }
+
+    /// Returns the registered [`Hook`], or [`None`] if this is the
+    /// [default hook](ExtensionPoint::default_hook).
+    #[must_use]
+    pub fn registered(&self) -> Option<&'a Hook<E>> {
+        match self {
+            Self::Registered(hook) => Some(hook),
+            Self::Default(_) => None,
+        }
+    }
+}
+
+/// Storage for the trait object of a [`Hook`], see [Ownership](Hook#ownership).
+#[derive(Debug)]
+enum HookInner<T: ?Sized> {
+    /// The hook owns its implementation
+    Owned(Box<T>),
+    /// The implementation may be shared with other hooks
+    Shared(Arc<T>),
 }
 
 impl<E: ExtensionPoint> PartialEq for Hook<E> {
@@ -311,7 +687,7 @@ impl<E: ExtensionPoint> PartialOrd for Hook<E> {
 
 impl<E: ExtensionPoint> Ord for Hook<E> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.name.cmp(other.name)
+        self.name.cmp(&other.name)
     }
 }
 
@@ -321,6 +697,7 @@ impl<E: ExtensionPoint> Hook<E> {
     /// # Parameters
     ///
     /// - `hook`: A boxed trait object implementing the extension point's trait
+    /// - `name`: A human readable name for the hook, either static or created at runtime
     ///
     /// # Examples
     ///
@@ -340,13 +717,123 @@ impl<E: ExtensionPoint> Hook<E> {
     /// }
     ///
     /// let hook = Hook::<Validator>::new(Box::new(LengthValidator), "myhook");
+    ///
+    /// let min_len = 5;
+    /// let hook = Hook::<Validator>::new(Box::new(LengthValidator), format!("longer than {min_len}"));
+    /// assert_eq!(hook.name(), "longer than 5");
+    /// ```
+    #[must_use]
+    pub fn new(hook: Box<E::HookTrait>, name: impl Into<Cow<'static, str>>) -> Self {
+        Hook {
+            inner: HookInner::Owned(hook),
+            hook_t: PhantomData,
+            name: name.into(),
+            priority: 0,
+        }
+    }
+
+    /// Creates a new hook with a shared trait implementation.
+    ///
+    /// The same implementation can be used by multiple hooks, see [Ownership](Hook#ownership).
+    ///
+    /// # Parameters
+    ///
+    /// - `hook`: A shared trait object implementing the extension point's trait
+    /// - `name`: A human readable name for the hook, either static or created at runtime
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use steckrs::{extension_point, hook::Hook};
+    ///
+    /// extension_point!(
+    ///     Reader: ReaderTrait;
+    ///     fn read(&self) -> u32;
+    /// );
+    ///
+    /// extension_point!(
+    ///     Describer: DescriberTrait;
+    ///     fn describe(&self) -> String;
+    /// );
+    ///
+    /// // one stateless implementation for both extension points
+    /// struct Sensor;
+    /// impl ReaderTrait for Sensor {
+    ///     fn read(&self) -> u32 {
+    ///         42
+    ///     }
+    /// }
+    /// impl DescriberTrait for Sensor {
+    ///     fn describe(&self) -> String {
+    ///         "a sensor".to_string()
+    ///     }
+    /// }
+    ///
+    /// let sensor = Arc::new(Sensor);
+    /// let reader = Hook::<Reader>::new_shared(sensor.clone(), "sensor");
+    /// let describer = Hook::<Describer>::new_shared(sensor, "sensor");
+    ///
+    /// assert!(reader.is_shared());
+    /// assert_eq!(reader.inner().read(), 42);
+    /// assert_eq!(describer.inner().describe(), "a sensor");
     /// ```
     #[must_use]
-    pub fn new(hook: Box<E::HookTrait>, name: PluginID) -> Self {
+    pub fn new_shared(hook: Arc<E::HookTrait>, name: impl Into<Cow<'static, str>>) -> Self {
         Hook {
-            inner: hook,
+            inner: HookInner::Shared(hook),
             hook_t: PhantomData,
-            name,
+            name: name.into(),
+            priority: 0,
+        }
+    }
+
+    /// Returns `true` if the implementation of this hook is shared, see
+    /// [Ownership](Hook#ownership).
+    #[must_use]
+    pub fn is_shared(&self) -> bool {
+        matches!(self.inner, HookInner::Shared(_))
+    }
+
+    /// Returns a new reference to the implementation of this hook, if it is
+    /// [shared](Hook#ownership).
+    ///
+    /// The returned [`Arc`] keeps the implementation alive independently of the hook, so it can
+    /// still be used after the hook was deregistered.
+    ///
+    /// Returns [`None`] if the hook owns its implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use steckrs::{extension_point, hook::Hook};
+    ///
+    /// extension_point!(
+    ///     Reader: ReaderTrait;
+    ///     fn read(&self) -> u32;
+    /// );
+    ///
+    /// struct Sensor;
+    /// impl ReaderTrait for Sensor {
+    ///     fn read(&self) -> u32 {
+    ///         42
+    ///     }
+    /// }
+    ///
+    /// let shared = Hook::<Reader>::new_shared(Arc::new(Sensor), "sensor");
+    /// let sensor = shared.shared_inner().unwrap();
+    /// drop(shared);
+    /// assert_eq!(sensor.read(), 42);
+    ///
+    /// let owned = Hook::<Reader>::new(Box::new(Sensor), "sensor");
+    /// assert!(owned.shared_inner().is_none());
+    /// ```
+    #[must_use]
+    pub fn shared_inner(&self) -> Option<Arc<E::HookTrait>> {
+        match &self.inner {
+            HookInner::Owned(_) => None,
+            HookInner::Shared(hook) => Some(Arc::clone(hook)),
         }
     }
 
@@ -374,7 +861,10 @@ impl<E: ExtensionPoint> Hook<E> {
     /// ```
     #[must_use]
     pub fn inner(&self) -> &E::HookTrait {
-        &self.inner
+        match &self.inner {
+            HookInner::Owned(hook) => hook,
+            HookInner::Shared(hook) => hook,
+        }
     }
 
     /// Returns a mutable reference to the inner trait implementation.
@@ -399,24 +889,148 @@ impl<E: ExtensionPoint> Hook<E> {
     /// let mut hook = Hook::<Calculator>::new(Box::new(SimpleCalculator), "myhook");
     /// assert_eq!(hook.inner_mut().add(2, 3), 5);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the implementation is [shared](Hook#ownership) and other references to it exist.
+    /// Use [`try_inner_mut`](Hook::try_inner_mut) if the hook might be shared. Hooks returned by
+    /// [`HookRegistry::get_by_extension_point_mut`] never panic here, as shared hooks that are
+    /// still in use are left out.
     #[must_use]
     pub fn inner_mut(&mut self) -> &mut E::HookTrait {
-        &mut self.inner
+        self.try_inner_mut()
+            .expect("cannot mutably access a hook implementation that is shared")
+    }
+
+    /// Returns a mutable reference to the inner trait implementation, if possible.
+    ///
+    /// This always succeeds for owned hooks. For [shared](Hook#ownership) hooks, this only
+    /// succeeds if no other references to the implementation exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use steckrs::{extension_point, hook::Hook};
+    ///
+    /// extension_point!(
+    ///     Counter: CounterTrait;
+    ///     fn increment(&mut self) -> u32;
+    /// );
+    ///
+    /// struct SimpleCounter(u32);
+    /// impl CounterTrait for SimpleCounter {
+    ///     fn increment(&mut self) -> u32 {
+    ///         self.0 += 1;
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// let counter: Arc<dyn CounterTrait> = Arc::new(SimpleCounter(0));
+    /// let mut hook = Hook::<Counter>::new_shared(counter.clone(), "counter");
+    ///
+    /// // the implementation is still referenced by `counter`
+    /// assert!(hook.try_inner_mut().is_none());
+    ///
+    /// drop(counter);
+    /// assert_eq!(hook.try_inner_mut().unwrap().increment(), 1);
+    /// ```
+    #[must_use]
+    pub fn try_inner_mut(&mut self) -> Option<&mut E::HookTrait> {
+        match &mut self.inner {
+            HookInner::Owned(hook) => Some(hook),
+            HookInner::Shared(hook) => Arc::get_mut(hook),
+        }
     }
 
     /// Get the human readable name for this hook
     #[must_use]
-    pub fn name(&self) -> &'static str {
-        self.name
+    pub fn name(&self) -> &str {
+        &self.name
     }
-}
 
-/// A type-erased hook that can be stored in a [`HookRegistry`].
-///
-/// [`BoxedHook`] acts as a type-erased container for [Hooks](Hook) of any [`ExtensionPoint`] type,
-/// allowing the [`HookRegistry`] to store hooks for different extension points in a
-/// single collection.
-///
+    /// Changes the human readable name for this hook.
+    ///
+    /// Hooks that are already registered can also be renamed with
+    /// [`HookRegistry::set_hook_name`].
+    pub fn set_name(&mut self, name: impl Into<Cow<'static, str>>) {
+        self.name = name.into();
+    }
+
+    /// Sets the priority of this hook.
+    ///
+    /// Hooks with a higher priority come first in
+    /// [`HookRegistry::get_by_extension_point`], regardless of the [`SortStrategy`], which only
+    /// orders hooks with the same priority. The default priority is `0`, negative priorities
+    /// move a hook behind the default.
+    ///
+    /// The priority is read when the hook is registered, so it has to be set before that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Command: CommandTrait;
+    ///     fn handles(&self, input: &str) -> bool;
+    /// );
+    ///
+    /// struct Fallback;
+    /// impl CommandTrait for Fallback {
+    ///     fn handles(&self, _input: &str) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// struct Quit;
+    /// impl CommandTrait for Quit {
+    ///     fn handles(&self, input: &str) -> bool {
+    ///         input == "quit"
+    ///     }
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// registry
+    ///     .register(
+    ///         &HookID::for_ep::<Command>("a_fallback_plugin", None),
+    ///         Hook::<Command>::new(Box::new(Fallback), "fallback").with_priority(-10),
+    ///     )
+    ///     .unwrap();
+    /// registry
+    ///     .register(
+    ///         &HookID::for_ep::<Command>("b_quit_plugin", None),
+    ///         Hook::<Command>::new(Box::new(Quit), "quit"),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let first = registry
+    ///     .get_by_extension_point::<Command>()
+    ///     .into_iter()
+    ///     .find(|(_id, hook)| hook.inner().handles("quit"))
+    ///     .unwrap();
+    /// assert_eq!(first.1.name(), "quit");
+    /// assert_eq!(first.1.priority(), 0);
+    /// ```
+    #[must_use]
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Returns the priority of this hook, see [`with_priority`](Self::with_priority).
+    #[must_use]
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+}
+
+/// A type-erased hook that can be stored in a [`HookRegistry`].
+///
+/// [`BoxedHook`] acts as a type-erased container for [Hooks](Hook) of any [`ExtensionPoint`] type,
+/// allowing the [`HookRegistry`] to store hooks for different extension points in a
+/// single collection.
+///
 /// # Examples
 ///
 /// This type is mainly used internally by the [`HookRegistry`], but here's how it works:
@@ -449,13 +1063,27 @@ impl<E: ExtensionPoint> Hook<E> {
 pub struct BoxedHook {
     /// The actual hook trait object, type-erased
     hook: Box<dyn Any + Send + Sync>,
-    hook_name: &'static str,
     eid: ExtensionPointID,
+    ep_name: &'static str,
+    /// Calls [`DescribableHook::describe`] on the type-erased hook, if it is describable
+    describe: Option<fn(&(dyn Any + Send + Sync)) -> Option<String>>,
+    /// Calls [`Hook::name`] on the type-erased hook
+    name: fn(&(dyn Any + Send + Sync)) -> &str,
+    /// Calls [`Hook::set_name`] on the type-erased hook
+    set_name: fn(&mut (dyn Any + Send + Sync), Cow<'static, str>),
+    /// Position in the order of registration, see [`SortStrategy::Insertion`]
+    seq: u64,
+    /// Copied from [`Hook::priority`]
+    priority: i32,
+    /// See [`HookRegistry::set_hook_enabled`]
+    enabled: bool,
+    /// Calls [`ExtensionPoint::validate_hook`] on the type-erased hook
+    validate: fn(&(dyn Any + Send + Sync)) -> Result<(), String>,
 }
 
 impl PartialEq for BoxedHook {
     fn eq(&self, other: &Self) -> bool {
-        self.name() == other.name()
+        self.name() == other.name() && self.eid() == other.eid()
     }
 }
 
@@ -469,7 +1097,9 @@ impl PartialOrd for BoxedHook {
 
 impl Ord for BoxedHook {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.name().cmp(other.name())
+        self.name()
+            .cmp(other.name())
+            .then_with(|| self.eid().cmp(&other.eid()))
     }
 }
 
@@ -507,9 +1137,71 @@ impl BoxedHook {
     #[must_use]
     pub fn new<E: ExtensionPoint>(hook: Hook<E>) -> Self {
         BoxedHook {
-            hook_name: hook.name(),
+            priority: hook.priority,
+            enabled: true,
             hook: Box::new(hook),
             eid: E::id(),
+            ep_name: E::name(),
+            describe: None,
+            name: |hook| hook.downcast_ref::<Hook<E>>().map_or("", Hook::name),
+            set_name: |hook, name| {
+                if let Some(hook) = hook.downcast_mut::<Hook<E>>() {
+                    hook.set_name(name);
+                }
+            },
+            seq: 0,
+            validate: |hook| match hook.downcast_ref::<Hook<E>>() {
+                Some(hook) => E::validate_hook(hook.inner()),
+                None => Ok(()),
+            },
+        }
+    }
+
+    /// Creates a new boxed hook from a typed hook, keeping the ability to
+    /// [describe](BoxedHook::description) it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::hook::{BoxedHook, DescribableHook, ExtensionPoint, Hook};
+    ///
+    /// pub trait TimerTrait: DescribableHook + Send + Sync {
+    ///     fn get_time(&self) -> u64;
+    /// }
+    ///
+    /// #[derive(Debug, Ord, Eq, PartialOrd, PartialEq)]
+    /// struct Timer;
+    /// impl ExtensionPoint for Timer {
+    ///     type HookTrait = dyn TimerTrait;
+    /// }
+    ///
+    /// struct FixedTimer;
+    /// impl TimerTrait for FixedTimer {
+    ///     fn get_time(&self) -> u64 {
+    ///         42
+    ///     }
+    /// }
+    /// impl DescribableHook for FixedTimer {
+    ///     fn describe(&self) -> String {
+    ///         "always returns 42".to_string()
+    ///     }
+    /// }
+    ///
+    /// let hook = Hook::<Timer>::new(Box::new(FixedTimer), "myhook");
+    /// let boxed_hook = BoxedHook::new_described(hook);
+    /// assert_eq!(boxed_hook.description().as_deref(), Some("always returns 42"));
+    /// ```
+    #[must_use]
+    pub fn new_described<E: ExtensionPoint>(hook: Hook<E>) -> Self
+    where
+        E::HookTrait: DescribableHook,
+    {
+        BoxedHook {
+            describe: Some(|hook| {
+                hook.downcast_ref::<Hook<E>>()
+                    .map(|hook| hook.inner().describe())
+            }),
+            ..Self::new(hook)
         }
     }
 
@@ -642,10 +1334,65 @@ impl BoxedHook {
         self.eid
     }
 
+    /// Returns the [name](ExtensionPoint::name) of the [`ExtensionPoint`] of this hook.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{BoxedHook, Hook, ExtensionPoint}};
+    ///
+    /// extension_point!(
+    ///     MyExt: MyExtTrait;
+    ///     fn do_something(&self) -> bool;
+    /// );
+    ///
+    /// struct MyImpl;
+    /// impl MyExtTrait for MyImpl {
+    ///     fn do_something(&self) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let boxed_hook = BoxedHook::new(Hook::<MyExt>::new(Box::new(MyImpl), "myhook"));
+    /// assert_eq!(boxed_hook.ep_name(), MyExt::name());
+    /// ```
+    #[must_use]
+    pub fn ep_name(&self) -> &'static str {
+        self.ep_name
+    }
+
     /// Get name of this hook
     #[must_use]
-    pub fn name(&self) -> &'static str {
-        self.hook_name
+    pub fn name(&self) -> &str {
+        (self.name)(self.hook.as_ref())
+    }
+
+    /// Returns the [priority](Hook::with_priority) of this hook.
+    #[must_use]
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Returns `false` if this hook was disabled with [`HookRegistry::set_hook_enabled`].
+    ///
+    /// Hooks are enabled when they are created.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Changes the name of this hook, and of the [`Hook`] inside of it.
+    pub fn set_name(&mut self, name: impl Into<Cow<'static, str>>) {
+        (self.set_name)(self.hook.as_mut(), name.into());
+    }
+
+    /// Returns the [description](DescribableHook::describe) of this hook.
+    ///
+    /// This is [`None`] unless the hook was created with [`BoxedHook::new_described`].
+    #[must_use]
+    pub fn description(&self) -> Option<String> {
+        self.describe
+            .and_then(|describe| describe(self.hook.as_ref()))
     }
 }
 
@@ -655,6 +1402,166 @@ impl Debug for BoxedHook {
     }
 }
 
+/// A cooperative time budget for executing hooks.
+///
+/// Rust can not interrupt a running function, so a [`HookBudget`] can not stop a hook that takes
+/// too long. Instead, hooks that get a budget passed can check [`is_exhausted`](Self::is_exhausted)
+/// voluntarily and cut their work short, and the host can stop calling further hooks once the
+/// budget is exhausted, see
+/// [`PluginManager::for_each_hook_budgeted`](crate::PluginManager::for_each_hook_budgeted).
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use steckrs::hook::HookBudget;
+///
+/// let budget = HookBudget::new(Duration::from_secs(60));
+/// assert!(!budget.is_exhausted());
+/// assert!(budget.remaining() <= Duration::from_secs(60));
+///
+/// let nothing = HookBudget::new(Duration::ZERO);
+/// assert!(nothing.is_exhausted());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HookBudget {
+    start: Instant,
+    limit: Duration,
+}
+
+impl HookBudget {
+    /// Creates a new budget of `limit`, starting now.
+    #[must_use]
+    pub fn new(limit: Duration) -> Self {
+        Self {
+            start: Instant::now(),
+            limit,
+        }
+    }
+
+    /// Returns the total time limit of this budget.
+    #[must_use]
+    pub fn limit(&self) -> Duration {
+        self.limit
+    }
+
+    /// Returns the time that has passed since the budget was created.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Returns the time that is left in this budget, or [`Duration::ZERO`] if it is exhausted.
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.limit.saturating_sub(self.elapsed())
+    }
+
+    /// Returns `true` if the time limit of this budget has been reached.
+    #[must_use]
+    pub fn is_exhausted(&self) -> bool {
+        self.elapsed() >= self.limit
+    }
+}
+
+/// State of a hook behind a [`RwLock`](std::sync::RwLock), for mutation through `&self`.
+///
+/// The methods of an [`ExtensionPoint`] usually take `&self`, so hooks are shared and can run
+/// concurrently. A hook that needs to change its state, like a cache for expensive computations,
+/// would otherwise have to be used through
+/// [`HookRegistry::get_by_extension_point_mut`], which needs exclusive access to the registry.
+///
+/// Implement the [`HookTrait`](ExtensionPoint::HookTrait) for `SyncHook<YourState>` and use
+/// [`read`](Self::read) and [`write`](Self::write) in its methods. Many readers can access the
+/// state at the same time, while a writer has exclusive access.
+///
+/// A poisoned lock is recovered from, since a panicking hook should not take down all later
+/// uses of the hook. The state may be inconsistent in that case.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use steckrs::{extension_point, hook::{Hook, HookID, HookRegistry, SyncHook}};
+///
+/// extension_point!(
+///     Calculator: CalculatorTrait;
+///     fn square(&self, n: u64) -> u64;
+/// );
+///
+/// impl CalculatorTrait for SyncHook<HashMap<u64, u64>> {
+///     fn square(&self, n: u64) -> u64 {
+///         if let Some(cached) = self.read().get(&n) {
+///             return *cached;
+///         }
+///         let result = n * n;
+///         self.write().insert(n, result);
+///         result
+///     }
+/// }
+///
+/// let mut registry = HookRegistry::new();
+/// let id = HookID::for_ep::<Calculator>("math_plugin", None);
+/// let hook = Hook::<Calculator>::new(Box::new(SyncHook::new(HashMap::new())), "cached");
+/// registry.register(&id, hook).unwrap();
+///
+/// let hooks = registry.get_by_extension_point::<Calculator>();
+/// let hook = hooks[0].1;
+/// assert_eq!(hook.inner().square(12), 144);
+/// assert_eq!(hook.inner().square(12), 144); // from the cache
+/// ```
+#[derive(Debug, Default)]
+pub struct SyncHook<T> {
+    state: std::sync::RwLock<T>,
+}
+
+impl<T> SyncHook<T> {
+    /// Creates a new [`SyncHook`] with the given state.
+    #[must_use]
+    pub fn new(state: T) -> Self {
+        Self {
+            state: std::sync::RwLock::new(state),
+        }
+    }
+
+    /// Locks the state for reading, blocking while a writer holds the lock.
+    pub fn read(&self) -> std::sync::RwLockReadGuard<'_, T> {
+        self.state
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Locks the state for writing, blocking while any reader or writer holds the lock.
+    pub fn write(&self) -> std::sync::RwLockWriteGuard<'_, T> {
+        self.state
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Returns a mutable reference to the state, without locking.
+    ///
+    /// This is possible when the hook is accessed mutably, e.g. through
+    /// [`HookRegistry::get_by_extension_point_mut`].
+    pub fn get_mut(&mut self) -> &mut T {
+        self.state
+            .get_mut()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Consumes the [`SyncHook`] and returns the state.
+    pub fn into_inner(self) -> T {
+        self.state
+            .into_inner()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl<T> From<T> for SyncHook<T> {
+    fn from(state: T) -> Self {
+        Self::new(state)
+    }
+}
+
 /// Registry for storing and retrieving hooks.
 ///
 /// The [`HookRegistry`] provides a central place to register, deregister, and
@@ -726,6 +1633,169 @@ impl Debug for BoxedHook {
 #[derive(Debug, Default)]
 pub struct HookRegistry {
     hooks: HashMap<ExtensionPointID, HashMap<HookID, BoxedHook>>,
+    gone_plugins: HashSet<PluginID>,
+    callbacks: RegistrationCallbacks,
+    sort_strategies: HashMap<ExtensionPointID, SortStrategy>,
+    next_seq: u64,
+    /// The hooks of each extension point in the order of its [`SortStrategy`]. The order is
+    /// computed when it is first needed, an empty cell marks it as stale.
+    order: HashMap<ExtensionPointID, OnceLock<Vec<HookID>>>,
+    /// How many hooks use each [name](Hook::name), to warn about duplicates on registration.
+    /// `None` after hooks may have been renamed, it is counted again with the next registration.
+    name_counts: Option<HashMap<String, usize>>,
+    /// Extension points that do not accept new hooks
+    closed: HashSet<ExtensionPointID>,
+}
+
+/// The hooks of one [`ExtensionPoint`], borrowed from a [`HookRegistry`].
+///
+/// Created by [`HookRegistry::view_by_extension_point`]. The hooks are in the order of the
+/// [`SortStrategy`] of the extension point.
+pub struct HookView<'a, E: ExtensionPoint> {
+    ids: &'a [HookID],
+    hooks: Option<&'a HashMap<HookID, BoxedHook>>,
+    marker: PhantomData<E>,
+}
+
+impl<'a, E: ExtensionPoint> HookView<'a, E> {
+    /// Returns the number of hooks in this view.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns `true` if there are no hooks in this view.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Returns the hook at `index`, or [`None`] if the index is out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<(&'a HookID, &'a Hook<E>)> {
+        self.lookup(self.ids.get(index)?)
+    }
+
+    /// Returns an iterator over the hooks in this view.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a HookID, &'a Hook<E>)> + '_ {
+        self.ids.iter().filter_map(|id| self.lookup(id))
+    }
+
+    /// Internal helper that finds a hook of this view in the registry.
+    fn lookup(&self, id: &HookID) -> Option<(&'a HookID, &'a Hook<E>)> {
+        let (id, hook) = self.hooks?.get_key_value(id)?;
+        Some((id, hook.downcast()?))
+    }
+}
+
+impl<'a, E: ExtensionPoint> IntoIterator for HookView<'a, E> {
+    type Item = (&'a HookID, &'a Hook<E>);
+    type IntoIter = HookViewIter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        HookViewIter { view: self, pos: 0 }
+    }
+}
+
+/// An iterator over the hooks of a [`HookView`] that owns the view.
+///
+/// Created by [`HookView::into_iter`] and [`HookRegistry::iter_by_extension_point`].
+pub struct HookViewIter<'a, E: ExtensionPoint> {
+    view: HookView<'a, E>,
+    pos: usize,
+}
+
+impl<'a, E: ExtensionPoint> Iterator for HookViewIter<'a, E> {
+    type Item = (&'a HookID, &'a Hook<E>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(id) = self.view.ids.get(self.pos) {
+            self.pos += 1;
+            if let Some(item) = self.view.lookup(id) {
+                return Some(item);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.view.len() - self.pos))
+    }
+}
+
+/// The order in which a [`HookRegistry`] returns the hooks of an [`ExtensionPoint`].
+///
+/// The strategy can be set per extension point with [`HookRegistry::set_sort_strategy`]. It is
+/// used by [`HookRegistry::get_by_extension_point`] and the methods built on it, like
+/// [`PluginManager::get_enabled_hooks_by_ep`](crate::PluginManager::get_enabled_hooks_by_ep).
+///
+/// Hooks with a higher [priority](Hook::with_priority) always come first, the strategy only
+/// orders hooks with the same priority. Every strategy gives a deterministic order. Where the
+/// strategy considers two hooks equal, they are ordered by [`HookID`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SortStrategy {
+    /// Sort by [`HookID`], which is by [`PluginID`] first
+    #[default]
+    Id,
+    /// Sort by [`HookID`], in reverse
+    IdReverse,
+    /// Sort by the [name](Hook::name) of the hooks
+    Name,
+    /// Sort in the order the hooks were registered, oldest first
+    Insertion,
+}
+
+impl SortStrategy {
+    /// Compares two hooks of the same [`ExtensionPoint`] according to this strategy.
+    fn compare(
+        self,
+        (a_id, a): (&HookID, &BoxedHook),
+        (b_id, b): (&HookID, &BoxedHook),
+    ) -> std::cmp::Ordering {
+        b.priority.cmp(&a.priority).then_with(|| match self {
+            Self::Id => a_id.cmp(b_id),
+            Self::IdReverse => b_id.cmp(a_id),
+            Self::Name => a.name().cmp(b.name()).then_with(|| a_id.cmp(b_id)),
+            Self::Insertion => a.seq.cmp(&b.seq).then_with(|| a_id.cmp(b_id)),
+        })
+    }
+}
+
+/// Size information about a [`HookRegistry`], see [`HookRegistry::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RegistryStats {
+    /// Number of [extension points](ExtensionPoint) with at least one hook
+    pub extension_points: usize,
+    /// Number of hooks over all extension points
+    pub total_hooks: usize,
+    /// Number of hooks of the extension point with the most hooks
+    pub max_hooks_in_one_ep: usize,
+    /// Number of distinct plugins that registered at least one hook
+    pub plugins_with_hooks: usize,
+}
+
+/// Callback that is called when a hook is registered or deregistered, see
+/// [`HookRegistry::on_register_for`].
+type RegistrationCallback = Box<dyn Fn(&HookID) + Send + Sync>;
+
+/// Registration callbacks of a [`HookRegistry`], by [`ExtensionPointID`].
+#[derive(Default)]
+struct RegistrationCallbacks(HashMap<ExtensionPointID, Vec<RegistrationCallback>>);
+
+impl RegistrationCallbacks {
+    fn call(&self, id: &HookID) {
+        for callback in self.0.get(&id.extension_point_id).into_iter().flatten() {
+            callback(id);
+        }
+    }
+}
+
+impl Debug for RegistrationCallbacks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(eid, callbacks)| (eid, callbacks.len())))
+            .finish()
+    }
 }
 
 impl HookRegistry {
@@ -742,11 +1812,24 @@ impl HookRegistry {
     pub fn new() -> Self {
         Self {
             hooks: HashMap::new(),
+            gone_plugins: HashSet::new(),
+            callbacks: RegistrationCallbacks::default(),
+            sort_strategies: HashMap::new(),
+            next_seq: 0,
+            order: HashMap::new(),
+            name_counts: None,
+            closed: HashSet::new(),
         }
     }
 
     /// Registers a hook with the given [`HookID`].
     ///
+    /// Returns the registry itself on success, so that multiple registrations can be chained.
+    ///
+    /// If another hook with the same [name](Hook::name) is already registered (for any
+    /// [`ExtensionPoint`]), a warning is logged. This is not an error, but hook names are meant to
+    /// tell hooks apart for humans. See also [`name_collisions`](Self::name_collisions).
+    ///
     /// # Type Parameters
     ///
     /// - `E`: The extension point type
@@ -758,7 +1841,12 @@ impl HookRegistry {
     ///
     /// # Errors
     ///
-    /// Returns a [`HookError::AlreadyRegistered`] if a hook with the same ID is already registered.
+    /// Returns a [`HookError`] if:
+    /// - A hook with the same ID is already registered ([`HookError::AlreadyRegistered`])
+    /// - The extension point was [closed](Self::close_extension_point)
+    ///   ([`HookError::ExtensionPointClosed`])
+    /// - The hook is rejected by [`ExtensionPoint::validate_hook`]
+    ///   ([`HookError::ValidationFailed`])
     ///
     /// # Examples
     ///
@@ -782,80 +1870,731 @@ impl HookRegistry {
     /// let id = HookID::new("byte_plugin", Serializer::id(), None);
     ///
     /// registry.register(&id, hook).unwrap();
+    ///
+    /// // Registrations can be chained
+    /// let id1 = HookID::new("byte_plugin", Serializer::id(), Some("one"));
+    /// let id2 = HookID::new("byte_plugin", Serializer::id(), Some("two"));
+    /// registry
+    ///     .register(&id1, Hook::<Serializer>::new(Box::new(ByteSerializer), "one"))
+    ///     .unwrap()
+    ///     .register(&id2, Hook::<Serializer>::new(Box::new(ByteSerializer), "two"))
+    ///     .unwrap();
+    /// assert_eq!(registry.get_by_extension_point::<Serializer>().len(), 3);
     /// ```
-    pub fn register<E: ExtensionPoint>(&mut self, id: &HookID, hook: Hook<E>) -> HookResult<()> {
-        if self.exists(id) {
-            return Err(HookError::AlreadyRegistered);
-        }
-
-        let boxed_hook = BoxedHook::new(hook);
-
-        self.hooks
-            .entry(E::id())
-            .or_default()
-            .insert(id.clone(), boxed_hook);
-
-        Ok(())
+    pub fn register<E: ExtensionPoint>(
+        &mut self,
+        id: &HookID,
+        hook: Hook<E>,
+    ) -> HookResult<&mut Self> {
+        self.register_boxed(id, BoxedHook::new(hook))
     }
 
-    /// Deregisters a hook by [`HookID`].
-    ///
-    /// # Parameters
+    /// Registers a hook that may have failed to construct.
     ///
-    /// - `id`: The ID of the hook to deregister
+    /// If `result` is [`Ok`], the hook is registered like with [`register`](Self::register). If it
+    /// is an [`Err`], the error is logged as a warning and the hook is skipped. This lets a plugin
+    /// with many optional hooks work with the hooks that could be created, instead of failing
+    /// completely.
     ///
     /// # Returns
     ///
-    /// - `Some(BoxedHook)` if the hook was found and removed
-    /// - `None` if no hook with the given ID was found
+    /// - `Ok(true)` if the hook was registered
+    /// - `Ok(false)` if the hook was skipped
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`HookError`] if:
+    /// - A hook with the same ID is already registered ([`HookError::AlreadyRegistered`])
+    /// - The extension point was [closed](Self::close_extension_point)
+    ///   ([`HookError::ExtensionPointClosed`])
+    /// - The hook is rejected by [`ExtensionPoint::validate_hook`]
+    ///   ([`HookError::ValidationFailed`])
     ///
     /// # Examples
     ///
     /// ```
-    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID, ExtensionPoint}};
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
     ///
     /// extension_point!(
-    ///     Parser: ParserTrait;
-    ///     fn parse(&self, input: &str) -> bool;
+    ///     Accelerator: AcceleratorTrait;
     /// );
     ///
-    /// struct SimpleParser;
-    /// impl ParserTrait for SimpleParser {
-    ///     fn parse(&self, _: &str) -> bool {
-    ///         true
-    ///     }
+    /// struct Gpu;
+    /// impl AcceleratorTrait for Gpu {}
+    ///
+    /// fn detect_gpu() -> Result<Hook<Accelerator>, String> {
+    ///     Err("no GPU found".to_string())
     /// }
     ///
     /// let mut registry = HookRegistry::new();
-    /// let hook = Hook::<Parser>::new(Box::new(SimpleParser), "myhook");
-    /// let id = HookID::new("parser_plugin", Parser::id(), None);
-    ///
-    /// registry.register(&id, hook).unwrap();
-    /// assert!(registry.exists(&id));
+    /// let id = HookID::for_ep::<Accelerator>("accelerator_plugin", Some("gpu"));
     ///
-    /// let removed = registry.deregister(&id);
-    /// assert!(removed.is_some());
-    /// assert!(!registry.exists(&id));
+    /// // logs a warning
+    /// assert!(!registry.register_optional(&id, detect_gpu()).unwrap());
+    /// assert!(registry.get_by_extension_point::<Accelerator>().is_empty());
     /// ```
-    pub fn deregister(&mut self, id: &HookID) -> Option<BoxedHook> {
-        let id = self.get_by_id(id)?.0.clone();
-        if let Some(h) = self.hooks.get_mut(&id.extension_point_id) {
-            h.remove(&id)
-        } else {
-            None
-        }
-    }
+    pub fn register_optional<E: ExtensionPoint, Error: std::fmt::Display>(
+        &mut self,
+        id: &HookID,
+        result: Result<Hook<E>, Error>,
+    ) -> HookResult<bool> {
+        match result {
+            Ok(hook) => {
+                self.register(id, hook)?;
+                Ok(true)
+            }
+            Err(e) => {
+                warn!("Skipping optional hook {id:?}, it could not be created: {e}");
+                Ok(false)
+            }
+        }
+    }
+
+    /// Registers a [describable](DescribableHook) hook with the given [`HookID`].
+    ///
+    /// This works like [`register`](Self::register), but the description of the hook stays
+    /// available through [`description`](Self::description).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`HookError`] if:
+    /// - A hook with the same ID is already registered ([`HookError::AlreadyRegistered`])
+    /// - The extension point was [closed](Self::close_extension_point)
+    ///   ([`HookError::ExtensionPointClosed`])
+    /// - The hook is rejected by [`ExtensionPoint::validate_hook`]
+    ///   ([`HookError::ValidationFailed`])
+    ///
+    /// # Examples
+    ///
+    /// See [`DescribableHook`].
+    pub fn register_described<E: ExtensionPoint>(
+        &mut self,
+        id: &HookID,
+        hook: Hook<E>,
+    ) -> HookResult<&mut Self>
+    where
+        E::HookTrait: DescribableHook,
+    {
+        self.register_boxed(id, BoxedHook::new_described(hook))
+    }
+
+    /// Registers an already boxed hook with the given [`HookID`].
+    ///
+    /// This works like [`register`](Self::register), but without the static type of the
+    /// [`ExtensionPoint`]. The hook is stored under its own [`eid`](BoxedHook::eid). This is meant
+    /// for dynamic scenarios, where hooks are created as [`BoxedHook`]s somewhere else, for example
+    /// in a dynamically loaded library.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`HookError`] if:
+    /// - The [`extension_point_id`](HookID::extension_point_id) of `id` is not the
+    ///   [`eid`](BoxedHook::eid) of the hook ([`HookError::ExtensionPointMismatch`])
+    /// - A hook with the same ID is already registered ([`HookError::AlreadyRegistered`])
+    /// - The extension point was [closed](Self::close_extension_point)
+    ///   ([`HookError::ExtensionPointClosed`])
+    /// - The hook is rejected by [`ExtensionPoint::validate_hook`]
+    ///   ([`HookError::ValidationFailed`])
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{BoxedHook, Hook, HookID, HookRegistry}};
+    ///
+    /// extension_point!(
+    ///     Timer: TimerTrait;
+    ///     fn get_time(&self) -> u64;
+    /// );
+    ///
+    /// struct SystemTimer;
+    /// impl TimerTrait for SystemTimer {
+    ///     fn get_time(&self) -> u64 {
+    ///         42
+    ///     }
+    /// }
+    ///
+    /// // somewhere else, the hook is created without the registry
+    /// let boxed = BoxedHook::new(Hook::<Timer>::new(Box::new(SystemTimer), "timer"));
+    ///
+    /// // the registry only needs what the boxed hook knows about itself
+    /// let mut registry = HookRegistry::new();
+    /// let id = HookID::new("timer_plugin", boxed.eid(), None);
+    /// registry.register_erased(&id, boxed).unwrap();
+    ///
+    /// assert_eq!(registry.get_by_extension_point::<Timer>().len(), 1);
+    /// ```
+    pub fn register_erased(&mut self, id: &HookID, hook: BoxedHook) -> HookResult<&mut Self> {
+        if id.extension_point_id != hook.eid() {
+            return Err(HookError::ExtensionPointMismatch);
+        }
+        self.register_boxed(id, hook)
+    }
+
+    /// Internal helper that stores an already boxed hook.
+    fn register_boxed(&mut self, id: &HookID, mut boxed_hook: BoxedHook) -> HookResult<&mut Self> {
+        if self.exists(id) {
+            return Err(HookError::AlreadyRegistered);
+        }
+        if self.closed.contains(&boxed_hook.eid()) {
+            return Err(HookError::ExtensionPointClosed);
+        }
+        if let Err(reason) = (boxed_hook.validate)(boxed_hook.hook.as_ref()) {
+            warn!(
+                "Hook {id:?} for {} was rejected: {reason}",
+                boxed_hook.ep_name()
+            );
+            return Err(HookError::ValidationFailed(reason));
+        }
+        // the plugin is back
+        self.gone_plugins.remove(id.plugin_id);
+
+        let name_counts = self.name_counts.get_or_insert_with(|| {
+            let mut counts = HashMap::new();
+            for hook in self.hooks.values().flat_map(HashMap::values) {
+                *counts.entry(hook.name().to_string()).or_default() += 1;
+            }
+            counts
+        });
+        let count = name_counts
+            .entry(boxed_hook.name().to_string())
+            .or_default();
+        if *count > 0 {
+            warn!(
+                "Registering hook {:?} with name '{}', which is already used by another hook",
+                id,
+                boxed_hook.name()
+            );
+        }
+        *count += 1;
+
+        boxed_hook.seq = self.next_seq;
+        self.next_seq += 1;
+        debug!(
+            plugin_id = id.plugin_id,
+            extension_point = boxed_hook.ep_name(),
+            discriminator = ?id.discriminator,
+            name = boxed_hook.name(),
+            "registered hook"
+        );
+        let eid = boxed_hook.eid();
+        self.hooks
+            .entry(eid)
+            .or_default()
+            .insert(id.clone(), boxed_hook);
+        self.mark_order_stale(eid);
+        self.callbacks.call(id);
+
+        Ok(self)
+    }
+
+    /// Registers a hook with a [shared](Hook#ownership) implementation with the given [`HookID`].
+    ///
+    /// This is a shorthand for registering a hook created with [`Hook::new_shared`]. The same
+    /// [`Arc`] can be registered for multiple [Extension Points](ExtensionPoint), as long as the
+    /// implementation implements their traits. The registry holds one reference to the
+    /// implementation per registered hook, and the implementation is dropped once all hooks using
+    /// it were deregistered and no other references remain.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`HookError`] if:
+    /// - A hook with the same ID is already registered ([`HookError::AlreadyRegistered`])
+    /// - The extension point was [closed](Self::close_extension_point)
+    ///   ([`HookError::ExtensionPointClosed`])
+    /// - The hook is rejected by [`ExtensionPoint::validate_hook`]
+    ///   ([`HookError::ValidationFailed`])
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use steckrs::{extension_point, hook::{HookRegistry, HookID, ExtensionPoint}};
+    ///
+    /// extension_point!(
+    ///     Reader: ReaderTrait;
+    ///     fn read(&self) -> u32;
+    /// );
+    ///
+    /// extension_point!(
+    ///     Describer: DescriberTrait;
+    ///     fn describe(&self) -> String;
+    /// );
+    ///
+    /// struct Sensor;
+    /// impl ReaderTrait for Sensor {
+    ///     fn read(&self) -> u32 {
+    ///         42
+    ///     }
+    /// }
+    /// impl DescriberTrait for Sensor {
+    ///     fn describe(&self) -> String {
+    ///         "a sensor".to_string()
+    ///     }
+    /// }
+    ///
+    /// let sensor = Arc::new(Sensor);
+    /// let mut registry = HookRegistry::new();
+    /// registry
+    ///     .register_shared::<Reader>(
+    ///         &HookID::new("sensor_plugin", Reader::id(), None),
+    ///         sensor.clone(),
+    ///         "sensor",
+    ///     )
+    ///     .unwrap()
+    ///     .register_shared::<Describer>(
+    ///         &HookID::new("sensor_plugin", Describer::id(), None),
+    ///         sensor.clone(),
+    ///         "sensor",
+    ///     )
+    ///     .unwrap();
+    ///
+    /// // one reference by us, one by each hook
+    /// assert_eq!(Arc::strong_count(&sensor), 3);
+    /// assert_eq!(registry.get_by_extension_point::<Reader>()[0].1.inner().read(), 42);
+    /// ```
+    pub fn register_shared<E: ExtensionPoint>(
+        &mut self,
+        id: &HookID,
+        hook: Arc<E::HookTrait>,
+        name: impl Into<Cow<'static, str>>,
+    ) -> HookResult<&mut Self> {
+        self.register(id, Hook::<E>::new_shared(hook, name))
+    }
+
+    /// Deregisters a hook by [`HookID`].
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The ID of the hook to deregister
+    ///
+    /// # Returns
+    ///
+    /// - `Some(BoxedHook)` if the hook was found and removed
+    /// - `None` if no hook with the given ID was found
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID, ExtensionPoint}};
+    ///
+    /// extension_point!(
+    ///     Parser: ParserTrait;
+    ///     fn parse(&self, input: &str) -> bool;
+    /// );
+    ///
+    /// struct SimpleParser;
+    /// impl ParserTrait for SimpleParser {
+    ///     fn parse(&self, _: &str) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// let hook = Hook::<Parser>::new(Box::new(SimpleParser), "myhook");
+    /// let id = HookID::new("parser_plugin", Parser::id(), None);
+    ///
+    /// registry.register(&id, hook).unwrap();
+    /// assert!(registry.exists(&id));
+    ///
+    /// let removed = registry.deregister(&id);
+    /// assert!(removed.is_some());
+    /// assert!(!registry.exists(&id));
+    /// ```
+    pub fn deregister(&mut self, id: &HookID) -> Option<BoxedHook> {
+        let id = self.get_by_id(id)?.0.clone();
+        let removed = self.hooks.get_mut(&id.extension_point_id)?.remove(&id);
+        if let Some(hook) = &removed {
+            debug!(
+                plugin_id = id.plugin_id,
+                extension_point = hook.ep_name(),
+                discriminator = ?id.discriminator,
+                name = hook.name(),
+                "deregistered hook"
+            );
+            self.mark_order_stale(id.extension_point_id);
+            if let Some(count) = self
+                .name_counts
+                .as_mut()
+                .and_then(|counts| counts.get_mut(hook.name()))
+            {
+                *count -= 1;
+            }
+            self.callbacks.call(&id);
+        }
+        removed
+    }
+
+    /// Closes the [`ExtensionPoint`] `E`, so that no more hooks can be registered for it.
+    ///
+    /// This is meant for extension points of a phase that is over, like setup, or whose set of
+    /// hooks is final after startup. Registering a hook for a closed extension point fails with
+    /// [`HookError::ExtensionPointClosed`]. The registered hooks stay, and can still be
+    /// deregistered.
+    ///
+    /// Extension points are open unless closed. Use
+    /// [`open_extension_point`](Self::open_extension_point) to accept hooks again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, error::HookError, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Startup: StartupTrait;
+    /// );
+    ///
+    /// struct Noop;
+    /// impl StartupTrait for Noop {}
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// let early = HookID::for_ep::<Startup>("early_plugin", None);
+    /// registry.register(&early, Hook::<Startup>::new(Box::new(Noop), "early")).unwrap();
+    ///
+    /// registry.close_extension_point::<Startup>();
+    /// assert!(registry.is_extension_point_closed::<Startup>());
+    ///
+    /// let late = HookID::for_ep::<Startup>("late_plugin", None);
+    /// let result = registry.register(&late, Hook::<Startup>::new(Box::new(Noop), "late"));
+    /// assert!(matches!(result, Err(HookError::ExtensionPointClosed)));
+    ///
+    /// registry.open_extension_point::<Startup>();
+    /// registry.register(&late, Hook::<Startup>::new(Box::new(Noop), "late")).unwrap();
+    /// ```
+    pub fn close_extension_point<E: ExtensionPoint>(&mut self) {
+        self.closed.insert(E::id());
+    }
+
+    /// Opens the [`ExtensionPoint`] `E` again, after it was closed with
+    /// [`close_extension_point`](Self::close_extension_point).
+    pub fn open_extension_point<E: ExtensionPoint>(&mut self) {
+        self.closed.remove(&E::id());
+    }
+
+    /// Returns `true` if the [`ExtensionPoint`] `E` was closed with
+    /// [`close_extension_point`](Self::close_extension_point).
+    #[must_use]
+    pub fn is_extension_point_closed<E: ExtensionPoint>(&self) -> bool {
+        self.closed.contains(&E::id())
+    }
+
+    /// Calls `f` with all [closed](Self::close_extension_point) extension points opened, and
+    /// closes them again afterwards.
+    ///
+    /// This is for putting back hooks that were registered before an extension point was closed,
+    /// like when the [`PluginManager`](crate::PluginManager) rebuilds the registry or restores a
+    /// plugin after a failed [swap](crate::PluginManager::swap_plugin). Closing an extension point
+    /// is meant to stop new hooks, not to lose the ones it already had.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Startup: StartupTrait;
+    /// );
+    ///
+    /// struct Noop;
+    /// impl StartupTrait for Noop {}
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// registry.close_extension_point::<Startup>();
+    ///
+    /// let id = HookID::for_ep::<Startup>("early_plugin", None);
+    /// registry
+    ///     .with_extension_points_open(|registry| {
+    ///         registry.register(&id, Hook::<Startup>::new(Box::new(Noop), "early"))?;
+    ///         Ok::<_, steckrs::error::HookError>(())
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert!(registry.exists(&id));
+    /// assert!(registry.is_extension_point_closed::<Startup>());
+    /// ```
+    pub fn with_extension_points_open<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let closed = std::mem::take(&mut self.closed);
+        let result = f(self);
+        self.closed.extend(closed);
+        result
+    }
+
+    /// Moves a registered hook to a new [`HookID`].
+    ///
+    /// Since the [`HookID`] identifies a hook in the registry, changing the
+    /// [`plugin_id`](HookID::plugin_id) or [`discriminator`](HookID::discriminator) of a
+    /// registered hook means removing it and registering it again. This does both at once, and
+    /// only after checking that it will work, so the hook is never lost. The hook keeps its place
+    /// for [`SortStrategy::Insertion`].
+    ///
+    /// Callbacks of [`on_register_for`](Self::on_register_for) are called for both IDs after the
+    /// hook was moved.
+    ///
+    /// # Errors
+    ///
+    /// Nothing is changed if an error is returned:
+    /// - [`HookError::NotFound`] if no hook is registered with the `old` ID
+    /// - [`HookError::ExtensionPointMismatch`] if `new` belongs to a different extension point
+    /// - [`HookError::AlreadyRegistered`] if another hook is registered with the `new` ID
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Exporter: ExporterTrait;
+    /// );
+    ///
+    /// struct CsvExporter;
+    /// impl ExporterTrait for CsvExporter {}
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// let old = HookID::for_ep::<Exporter>("export_plugin", None);
+    /// registry.register(&old, Hook::<Exporter>::new(Box::new(CsvExporter), "csv")).unwrap();
+    ///
+    /// let new = HookID::for_ep::<Exporter>("export_plugin", Some("csv"));
+    /// registry.remap_hook(&old, new.clone()).unwrap();
+    ///
+    /// assert!(!registry.exists(&old));
+    /// assert!(registry.exists(&new));
+    /// ```
+    pub fn remap_hook(&mut self, old: &HookID, new: HookID) -> HookResult<()> {
+        if !self.exists(old) {
+            return Err(HookError::NotFound);
+        }
+        if *old == new {
+            return Ok(());
+        }
+        if old.extension_point_id != new.extension_point_id {
+            return Err(HookError::ExtensionPointMismatch);
+        }
+        if self.exists(&new) {
+            return Err(HookError::AlreadyRegistered);
+        }
+
+        let eid = old.extension_point_id;
+        let hooks = self.hooks.entry(eid).or_default();
+        let Some(hook) = hooks.remove(old) else {
+            return Err(HookError::NotFound);
+        };
+        self.gone_plugins.remove(new.plugin_id);
+        let new_id = new.clone();
+        hooks.insert(new, hook);
+        self.mark_order_stale(eid);
+        self.callbacks.call(old);
+        self.callbacks.call(&new_id);
+        Ok(())
+    }
+
+    /// Internal helper that marks the order of the hooks of an extension point as stale, so it
+    /// is sorted again when it is needed next.
+    fn mark_order_stale(&mut self, eid: ExtensionPointID) {
+        self.order.insert(eid, OnceLock::new());
+    }
+
+    /// Internal helper that forgets what depends on the names of the hooks of an extension point,
+    /// before handing out mutable access to them.
+    ///
+    /// Renaming changes the order of [`SortStrategy::Name`], and the count of hooks per name.
+    fn mark_renamed(&mut self, eid: ExtensionPointID) {
+        self.mark_order_stale(eid);
+        self.name_counts = None;
+    }
+
+    /// Internal helper that returns the hooks of an extension point in the order of its
+    /// [`SortStrategy`], and sorts them first if the order is stale.
+    fn order_of(&self, eid: ExtensionPointID) -> &[HookID] {
+        match self.order.get(&eid) {
+            Some(order) => order.get_or_init(|| self.sorted_ids(eid)),
+            None => &[],
+        }
+    }
+
+    /// Internal helper that sorts the hooks of an extension point according to its
+    /// [`SortStrategy`].
+    fn sorted_ids(&self, eid: ExtensionPointID) -> Vec<HookID> {
+        let Some(hooks) = self.hooks.get(&eid) else {
+            return Vec::new();
+        };
+        let strategy = self.sort_strategies.get(&eid).copied().unwrap_or_default();
+        let mut v: Vec<(&HookID, &BoxedHook)> = hooks.iter().collect();
+        v.sort_by(|a, b| strategy.compare(*a, *b));
+        v.into_iter().map(|(id, _hook)| id.clone()).collect()
+    }
+
+    /// Adds a callback that is called whenever a hook for the [`ExtensionPoint`] `E` is registered
+    /// or deregistered.
+    ///
+    /// The callback gets the [`HookID`] of the hook, and is called after the registry changed.
+    /// This is useful for subsystems that keep a cache of the hooks of their extension point, and
+    /// need to know when to rebuild it.
+    ///
+    /// Callbacks can not be removed, and they stay with the registry for its whole lifetime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use steckrs::{extension_point, hook::HookRegistry, register_hook};
+    ///
+    /// extension_point!(
+    ///     Handler: HandlerTrait;
+    ///     fn handle(&self, input: &str) -> String;
+    /// );
+    ///
+    /// extension_point!(
+    ///     Other: OtherTrait;
+    /// );
+    ///
+    /// struct EchoHandler;
+    /// impl HandlerTrait for EchoHandler {
+    ///     fn handle(&self, input: &str) -> String {
+    ///         input.to_string()
+    ///     }
+    /// }
+    ///
+    /// struct Nothing;
+    /// impl OtherTrait for Nothing {}
+    ///
+    /// let changes = Arc::new(AtomicUsize::new(0));
+    /// let mut registry = HookRegistry::new();
+    ///
+    /// let counter = changes.clone();
+    /// registry.on_register_for::<Handler>(move |_id| {
+    ///     counter.fetch_add(1, Ordering::Relaxed);
+    /// });
+    ///
+    /// register_hook!(registry, "echo_plugin", Handler, EchoHandler);
+    /// register_hook!(registry, "echo_plugin", Other, Nothing);
+    /// assert_eq!(changes.load(Ordering::Relaxed), 1);
+    ///
+    /// registry.deregister_hooks_for_plugin("echo_plugin");
+    /// assert_eq!(changes.load(Ordering::Relaxed), 2);
+    /// ```
+    pub fn on_register_for<E: ExtensionPoint>(
+        &mut self,
+        cb: impl Fn(&HookID) + Send + Sync + 'static,
+    ) {
+        self.callbacks
+            .0
+            .entry(E::id())
+            .or_default()
+            .push(Box::new(cb));
+    }
 
     /// Checks if a hook with the given [`HookID`] exists.
     ///
-    /// # Parameters
+    /// # Parameters
+    ///
+    /// - `id`: The ID to check
+    ///
+    /// # Returns
+    ///
+    /// - `true` if a hook with the given ID exists
+    /// - `false` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID, ExtensionPoint}};
+    ///
+    /// extension_point!(
+    ///     Handler: HandlerTrait;
+    ///     fn handle(&self, input: &str) -> String;
+    /// );
+    ///
+    /// struct EchoHandler;
+    /// impl HandlerTrait for EchoHandler {
+    ///     fn handle(&self, input: &str) -> String {
+    ///         input.to_string()
+    ///     }
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// let hook = Hook::<Handler>::new(Box::new(EchoHandler), "myhook");
+    /// let id = HookID::new("echo_plugin", Handler::id(), None);
+    ///
+    /// assert!(!registry.exists(&id));
+    /// registry.register(&id, hook).unwrap();
+    /// assert!(registry.exists(&id));
+    /// ```
+    #[must_use]
+    pub fn exists(&self, id: &HookID) -> bool {
+        self.get_by_id(id).is_some()
+    }
+
+    /// Returns a discriminator that is not yet used by a hook of a plugin for the
+    /// [`ExtensionPoint`] `E`.
+    ///
+    /// If the plugin has no hook without discriminator for `E`, this is [`None`], so a plugin
+    /// with a single hook per extension point needs no discriminator. Otherwise, it is the
+    /// smallest number, starting at `1`, that is neither used as discriminator yet nor listed in
+    /// `reserved`. This is how [`simple_plugin!`](crate::simple_plugin) tells apart several hooks
+    /// of one extension point that were listed without a discriminator, reserving the
+    /// discriminators of the hooks that have one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Handler: HandlerTrait;
+    /// );
+    ///
+    /// struct EchoHandler;
+    /// impl HandlerTrait for EchoHandler {}
     ///
-    /// - `id`: The ID to check
+    /// let mut registry = HookRegistry::new();
+    /// // "1" is going to be used by another hook
+    /// for expected in [None, Some("2"), Some("3")] {
+    ///     let discriminator = registry.free_discriminator::<Handler>("echo_plugin", &["1"]);
+    ///     assert_eq!(discriminator.as_deref(), expected);
+    ///
+    ///     let id = HookID::for_ep::<Handler>("echo_plugin", discriminator.as_deref());
+    ///     registry.register(&id, Hook::<Handler>::new(Box::new(EchoHandler), "echo")).unwrap();
+    /// }
+    /// ```
+    #[must_use]
+    pub fn free_discriminator<E: ExtensionPoint>(
+        &self,
+        plugin_id: PluginID,
+        reserved: &[&str],
+    ) -> Option<String> {
+        if !self.exists(&HookID::for_ep::<E>(plugin_id, None)) {
+            return None;
+        }
+        // the hook without discriminator is counted too, so one of these is free
+        (1..=self.count_for_extension_point::<E>() + reserved.len())
+            .map(|n| n.to_string())
+            .find(|discriminator| {
+                !reserved.contains(&discriminator.as_str())
+                    && !self.exists(&HookID::for_ep::<E>(plugin_id, Some(discriminator)))
+            })
+    }
+
+    /// Returns the [description](DescribableHook::describe) of the hook with the given
+    /// [`HookID`].
     ///
     /// # Returns
     ///
-    /// - `true` if a hook with the given ID exists
-    /// - `false` otherwise
+    /// - `Some(String)` if the hook exists and was registered with
+    ///   [`register_described`](Self::register_described)
+    /// - `None` otherwise
+    ///
+    /// # Examples
+    ///
+    /// See [`DescribableHook`].
+    #[must_use]
+    pub fn description(&self, id: &HookID) -> Option<String> {
+        self.get_by_id(id)?.1.description()
+    }
+
+    /// Gets all hook names that are used by more than one registered hook.
+    ///
+    /// Hook names are meant to tell hooks apart for humans, so a name used multiple times is
+    /// likely a mistake. The returned names are sorted and contain no duplicates.
     ///
     /// # Examples
     ///
@@ -875,16 +2614,295 @@ impl HookRegistry {
     /// }
     ///
     /// let mut registry = HookRegistry::new();
-    /// let hook = Hook::<Handler>::new(Box::new(EchoHandler), "myhook");
-    /// let id = HookID::new("echo_plugin", Handler::id(), None);
+    /// let id1 = HookID::new("plugin1", Handler::id(), None);
+    /// let id2 = HookID::new("plugin2", Handler::id(), None);
     ///
-    /// assert!(!registry.exists(&id));
-    /// registry.register(&id, hook).unwrap();
-    /// assert!(registry.exists(&id));
+    /// registry.register(&id1, Hook::<Handler>::new(Box::new(EchoHandler), "echo")).unwrap();
+    /// assert!(registry.name_collisions().is_empty());
+    ///
+    /// // logs a warning, but works
+    /// registry.register(&id2, Hook::<Handler>::new(Box::new(EchoHandler), "echo")).unwrap();
+    /// assert_eq!(registry.name_collisions(), vec!["echo"]);
     /// ```
     #[must_use]
-    pub fn exists(&self, id: &HookID) -> bool {
-        self.get_by_id(id).is_some()
+    pub fn name_collisions(&self) -> Vec<&str> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for hook in self.hooks.values().flat_map(HashMap::values) {
+            *counts.entry(hook.name()).or_default() += 1;
+        }
+        let mut names: Vec<&str> = counts
+            .into_iter()
+            .filter(|(_name, count)| *count > 1)
+            .map(|(name, _count)| name)
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Computes a fingerprint of the structure of this registry.
+    ///
+    /// The fingerprint is a hash over the [`HookID`]s and [names](Hook::name) of all registered
+    /// hooks. If two fingerprints are equal, the registry contained the same hooks (with high
+    /// probability). This is cheaper to keep around than a copy of all [`HookID`]s, when you only
+    /// need to know whether the set of hooks changed between two points in time.
+    ///
+    /// Unlike the [`generation`](crate::PluginManager::generation) of a
+    /// [`PluginManager`](crate::PluginManager), the fingerprint only reflects the net change:
+    /// registering and then deregistering the same hook yields the same fingerprint again.
+    ///
+    /// Fingerprints are only comparable within the same build of a program, as they include
+    /// [`ExtensionPointID`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Handler: HandlerTrait;
+    ///     fn handle(&self, input: &str) -> String;
+    /// );
+    ///
+    /// struct EchoHandler;
+    /// impl HandlerTrait for EchoHandler {
+    ///     fn handle(&self, input: &str) -> String {
+    ///         input.to_string()
+    ///     }
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// let before = registry.fingerprint();
+    ///
+    /// let id = HookID::for_ep::<Handler>("echo_plugin", None);
+    /// registry.register(&id, Hook::<Handler>::new(Box::new(EchoHandler), "echo")).unwrap();
+    /// assert_ne!(registry.fingerprint(), before);
+    ///
+    /// registry.deregister(&id);
+    /// assert_eq!(registry.fingerprint(), before);
+    /// ```
+    #[must_use]
+    pub fn fingerprint(&self) -> u64 {
+        let mut entries: Vec<(&HookID, &str)> = self
+            .hooks
+            .values()
+            .flatten()
+            .map(|(id, hook)| (id, hook.name()))
+            .collect();
+        entries.sort_unstable();
+
+        let mut hasher = std::hash::DefaultHasher::new();
+        entries.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Gets the [names](ExtensionPoint::name) of all extension points that have hooks, by
+    /// [`ExtensionPointID`].
+    ///
+    /// An [`ExtensionPointID`] is a [`TypeId`](std::any::TypeId), which cannot be turned into a
+    /// name by itself. Use this to show the extension point of a [`HookID`] in logs or user
+    /// interfaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{ExtensionPoint, HookRegistry}, register_hook};
+    ///
+    /// extension_point!(
+    ///     Logger: LoggerTrait;
+    /// );
+    ///
+    /// struct Noop;
+    /// impl LoggerTrait for Noop {}
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// register_hook!(registry, "log_plugin", Logger, Noop);
+    ///
+    /// let names = registry.extension_point_names();
+    /// let (id, _hook) = registry.get_by_plugin("log_plugin")[0];
+    /// assert_eq!(names[&id.extension_point_id], Logger::name());
+    /// ```
+    #[must_use]
+    pub fn extension_point_names(&self) -> HashMap<ExtensionPointID, &'static str> {
+        self.hooks
+            .iter()
+            .filter_map(|(eid, hooks)| Some((*eid, hooks.values().next()?.ep_name())))
+            .collect()
+    }
+
+    /// Gets a rough overview of the size of the registry, see [`RegistryStats`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Logger: LoggerTrait;
+    /// );
+    /// extension_point!(
+    ///     Renderer: RendererTrait;
+    /// );
+    ///
+    /// struct Noop;
+    /// impl LoggerTrait for Noop {}
+    /// impl RendererTrait for Noop {}
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// for plugin in ["plugin1", "plugin2"] {
+    ///     let id = HookID::for_ep::<Logger>(plugin, None);
+    ///     registry.register(&id, Hook::<Logger>::new(Box::new(Noop), "log")).unwrap();
+    /// }
+    /// let id = HookID::for_ep::<Renderer>("plugin1", None);
+    /// registry.register(&id, Hook::<Renderer>::new(Box::new(Noop), "render")).unwrap();
+    ///
+    /// let stats = registry.stats();
+    /// assert_eq!(stats.extension_points, 2);
+    /// assert_eq!(stats.total_hooks, 3);
+    /// assert_eq!(stats.max_hooks_in_one_ep, 2);
+    /// assert_eq!(stats.plugins_with_hooks, 2);
+    /// ```
+    #[must_use]
+    pub fn stats(&self) -> RegistryStats {
+        let mut stats = RegistryStats::default();
+        let mut plugins = HashSet::new();
+        for hooks in self.hooks.values().filter(|hooks| !hooks.is_empty()) {
+            stats.extension_points += 1;
+            stats.total_hooks += hooks.len();
+            stats.max_hooks_in_one_ep = stats.max_hooks_in_one_ep.max(hooks.len());
+            plugins.extend(hooks.keys().map(|id| id.plugin_id));
+        }
+        stats.plugins_with_hooks = plugins.len();
+        stats
+    }
+
+    /// Returns the number of hooks over all extension points.
+    ///
+    /// This is the same as the `total_hooks` of [`stats`](Self::stats), but cheaper.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Logger: LoggerTrait;
+    /// );
+    /// extension_point!(
+    ///     Renderer: RendererTrait;
+    /// );
+    ///
+    /// struct Noop;
+    /// impl LoggerTrait for Noop {}
+    /// impl RendererTrait for Noop {}
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// assert!(registry.is_empty());
+    ///
+    /// for plugin in ["plugin1", "plugin2"] {
+    ///     let id = HookID::for_ep::<Logger>(plugin, None);
+    ///     registry.register(&id, Hook::<Logger>::new(Box::new(Noop), "log")).unwrap();
+    /// }
+    /// let id = HookID::for_ep::<Renderer>("plugin1", None);
+    /// registry.register(&id, Hook::<Renderer>::new(Box::new(Noop), "render")).unwrap();
+    ///
+    /// assert_eq!(registry.len(), 3);
+    /// assert!(!registry.is_empty());
+    /// assert_eq!(registry.count_for_extension_point::<Logger>(), 2);
+    /// assert_eq!(registry.count_for_extension_point::<Renderer>(), 1);
+    /// ```
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.hooks.values().map(HashMap::len).sum()
+    }
+
+    /// Returns `true` if no hooks are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.hooks.values().all(HashMap::is_empty)
+    }
+
+    /// Returns the number of hooks registered for a specific [`ExtensionPoint`] type.
+    #[must_use]
+    pub fn count_for_extension_point<E: ExtensionPoint>(&self) -> usize {
+        self.hooks.get(&E::id()).map_or(0, HashMap::len)
+    }
+
+    /// Checks the internal consistency of the registry.
+    ///
+    /// This checks that:
+    /// - Every hook is stored under the extension point of the hook and of its [`HookID`]
+    /// - All hooks of an extension point report the same [name](ExtensionPoint::name)
+    /// - No two extension points have the same name
+    ///
+    /// The registry upholds the first two itself, so this is mostly a self-check for debugging.
+    /// The last one can be broken by overriding [`ExtensionPoint::name`], which makes lookups by
+    /// name, like [`get_by_extension_point_name`](Self::get_by_extension_point_name), ambiguous.
+    ///
+    /// # Errors
+    ///
+    /// Returns every [`IntegrityIssue`] that was found, if there is at least one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Logger: LoggerTrait;
+    /// );
+    ///
+    /// struct Noop;
+    /// impl LoggerTrait for Noop {}
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// let id = HookID::for_ep::<Logger>("log_plugin", None);
+    /// registry.register(&id, Hook::<Logger>::new(Box::new(Noop), "log")).unwrap();
+    ///
+    /// assert!(registry.validate_integrity().is_ok());
+    /// ```
+    pub fn validate_integrity(&self) -> Result<(), Vec<IntegrityIssue>> {
+        let mut issues = Vec::new();
+        let mut eids_by_name: HashMap<&'static str, usize> = HashMap::new();
+        for (eid, hooks) in &self.hooks {
+            let mut names: Vec<&'static str> = Vec::new();
+            for (id, hook) in hooks {
+                let actual = if hook.eid() == *eid {
+                    id.extension_point_id
+                } else {
+                    hook.eid()
+                };
+                if actual != *eid {
+                    issues.push(IntegrityIssue::MisfiledHook {
+                        id: id.clone(),
+                        stored_under: *eid,
+                        actual,
+                    });
+                }
+                if !names.contains(&hook.ep_name()) {
+                    names.push(hook.ep_name());
+                }
+            }
+            for name in &names {
+                *eids_by_name.entry(name).or_default() += 1;
+            }
+            if names.len() > 1 {
+                names.sort_unstable();
+                issues.push(IntegrityIssue::ConflictingNames {
+                    extension_point: *eid,
+                    names,
+                });
+            }
+        }
+        for (name, count) in eids_by_name {
+            if count > 1 {
+                issues.push(IntegrityIssue::DuplicateName { name, count });
+            }
+        }
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
     }
 
     /// Gets a hook by [`HookID`] and extension point type.
@@ -984,6 +3002,7 @@ impl HookRegistry {
     /// ```
     #[must_use]
     pub fn get_mut<E: ExtensionPoint>(&mut self, id: &HookID) -> Option<&mut Hook<E>> {
+        self.mark_renamed(E::id());
         match self.hooks.get_mut(&E::id()) {
             Some(hooks) => {
                 let boxed_hook = hooks.get_mut(id)?;
@@ -993,6 +3012,96 @@ impl HookRegistry {
         }
     }
 
+    /// Gets a mutable hook of one [`ExtensionPoint`] and a hook of another [`ExtensionPoint`] at
+    /// the same time.
+    ///
+    /// Hooks of different extension points are stored separately in the registry, so one can be
+    /// borrowed mutably while the other is borrowed immutably.
+    ///
+    /// `A` and `B` must be different extension point types. If they are the same, the second hook
+    /// can not be borrowed at the same time, and `None` is returned for it.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The extension point type of the mutable hook
+    /// - `B`: The extension point type of the immutable hook
+    ///
+    /// # Parameters
+    ///
+    /// - `a_id`: The ID of the hook to get mutably
+    /// - `b_id`: The ID of the hook to get immutably
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the hooks, each `None` if no hook with the given ID was found for its
+    /// extension point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID, ExtensionPoint}};
+    ///
+    /// extension_point!(
+    ///     Counter: CounterTrait;
+    ///     fn add(&mut self, amount: u32) -> u32;
+    /// );
+    ///
+    /// extension_point!(
+    ///     Step: StepTrait;
+    ///     fn step(&self) -> u32;
+    /// );
+    ///
+    /// struct SimpleCounter(u32);
+    /// impl CounterTrait for SimpleCounter {
+    ///     fn add(&mut self, amount: u32) -> u32 {
+    ///         self.0 += amount;
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// struct FixedStep;
+    /// impl StepTrait for FixedStep {
+    ///     fn step(&self) -> u32 {
+    ///         5
+    ///     }
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// let counter_id = HookID::new("plugin", Counter::id(), None);
+    /// let step_id = HookID::new("plugin", Step::id(), None);
+    /// registry
+    ///     .register(&counter_id, Hook::<Counter>::new(Box::new(SimpleCounter(0)), "counter"))
+    ///     .unwrap()
+    ///     .register(&step_id, Hook::<Step>::new(Box::new(FixedStep), "step"))
+    ///     .unwrap();
+    ///
+    /// let (counter, step) = registry.get_mut_and::<Counter, Step>(&counter_id, &step_id);
+    /// let (counter, step) = (counter.unwrap(), step.unwrap());
+    /// assert_eq!(counter.inner_mut().add(step.inner().step()), 5);
+    /// ```
+    #[must_use]
+    pub fn get_mut_and<A: ExtensionPoint, B: ExtensionPoint>(
+        &mut self,
+        a_id: &HookID,
+        b_id: &HookID,
+    ) -> (Option<&mut Hook<A>>, Option<&Hook<B>>) {
+        if A::id() == B::id() {
+            return (self.get_mut(a_id), None);
+        }
+
+        self.mark_renamed(A::id());
+        let mut a = None;
+        let mut b = None;
+        for (eid, hooks) in &mut self.hooks {
+            if *eid == A::id() {
+                a = hooks.get_mut(a_id).and_then(BoxedHook::downcast_mut);
+            } else if *eid == B::id() {
+                b = hooks.get(b_id).and_then(BoxedHook::downcast);
+            }
+        }
+        (a, b)
+    }
+
     /// Gets a hook by [`HookID`].
     ///
     /// # Parameters
@@ -1032,7 +3141,7 @@ impl HookRegistry {
     /// ```
     #[must_use]
     pub fn get_by_id(&self, id: &HookID) -> Option<(&HookID, &BoxedHook)> {
-        self.get_by_filter(|(hid, _)| *hid == id).first().copied()
+        self.hooks.get(&id.extension_point_id)?.get_key_value(id)
     }
 
     /// Gets a mutable hook by [`HookID`].
@@ -1073,10 +3182,143 @@ impl HookRegistry {
     /// assert!(retrieved.is_some());
     /// ```
     #[must_use]
-    pub fn get_by_id_mut(&mut self, id: &HookID) -> Option<(&HookID, &mut BoxedHook)> {
-        self.get_by_filter_mut(|(hid, _)| *hid == id)
-            .into_iter()
-            .next()
+    pub fn get_by_id_mut(&mut self, id: &HookID) -> Option<(&HookID, &mut BoxedHook)> {
+        self.mark_renamed(id.extension_point_id);
+        self.hooks
+            .get_mut(&id.extension_point_id)?
+            .iter_mut()
+            .find(|(hid, _hook)| *hid == id)
+    }
+
+    /// Changes the [name](Hook::name) of a registered hook.
+    ///
+    /// The name is changed for both the [`BoxedHook`] and the typed [`Hook`] inside of it.
+    ///
+    /// The new name can be created at runtime, like from user input.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the hook exists and was renamed
+    /// - `false` if no hook with the given ID was found
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Action: ActionTrait;
+    ///     fn run(&self);
+    /// );
+    ///
+    /// struct Noop;
+    /// impl ActionTrait for Noop {
+    ///     fn run(&self) {}
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// let id = HookID::for_ep::<Action>("action_plugin", None);
+    /// registry.register(&id, Hook::<Action>::new(Box::new(Noop), "noop")).unwrap();
+    ///
+    /// let user_input = String::from("Do nothing");
+    /// assert!(registry.set_hook_name(&id, user_input));
+    /// assert_eq!(registry.get::<Action>(&id).unwrap().name(), "Do nothing");
+    /// assert_eq!(registry.get_by_id(&id).unwrap().1.name(), "Do nothing");
+    ///
+    /// let missing = HookID::for_ep::<Action>("other_plugin", None);
+    /// assert!(!registry.set_hook_name(&missing, "whatever"));
+    /// ```
+    pub fn set_hook_name(&mut self, id: &HookID, name: impl Into<Cow<'static, str>>) -> bool {
+        let Some(hook) = self
+            .hooks
+            .get_mut(&id.extension_point_id)
+            .and_then(|hooks| hooks.get_mut(id))
+        else {
+            return false;
+        };
+        hook.set_name(name);
+        self.mark_renamed(id.extension_point_id);
+        true
+    }
+
+    /// Enables or disables a single registered hook.
+    ///
+    /// This is independent of the enabled state of the [Plugin](crate::Plugin) that registered
+    /// the hook: a plugin can stay enabled while some of its hooks are switched off. Disabled
+    /// hooks stay in the registry and are still returned by the methods of the registry, but
+    /// [`PluginManager::get_enabled_hooks_by_ep`](crate::PluginManager::get_enabled_hooks_by_ep)
+    /// and the methods built on it skip them. Hooks are enabled when they are registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`HookError::NotFound`] if no hook with the given ID is registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager, hook::HookID};
+    ///
+    /// extension_point!(
+    ///     Shortcut: ShortcutTrait;
+    ///     fn key(&self) -> char;
+    /// );
+    ///
+    /// struct Save;
+    /// impl ShortcutTrait for Save {
+    ///     fn key(&self) -> char {
+    ///         's'
+    ///     }
+    /// }
+    ///
+    /// struct Quit;
+    /// impl ShortcutTrait for Quit {
+    ///     fn key(&self) -> char {
+    ///         'q'
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     ShortcutPlugin,
+    ///     "shortcut_plugin",
+    ///     "Keyboard shortcuts",
+    ///     hooks: [(Shortcut, Save, "save"), (Shortcut, Quit, "quit")]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ShortcutPlugin::new())).unwrap();
+    /// manager.enable_plugin(ShortcutPlugin::ID).unwrap();
+    ///
+    /// let quit = HookID::for_ep::<Shortcut>(ShortcutPlugin::ID, Some("quit"));
+    /// manager.hook_registry_mut().set_hook_enabled(&quit, false).unwrap();
+    /// assert_eq!(manager.hook_registry().is_hook_enabled(&quit), Some(false));
+    ///
+    /// let keys: Vec<char> = manager
+    ///     .get_enabled_hooks_by_ep::<Shortcut>()
+    ///     .iter()
+    ///     .map(|(_id, hook)| hook.inner().key())
+    ///     .collect();
+    /// assert_eq!(keys, ['s']);
+    /// ```
+    pub fn set_hook_enabled(&mut self, id: &HookID, enabled: bool) -> HookResult<()> {
+        let hook = self
+            .hooks
+            .get_mut(&id.extension_point_id)
+            .and_then(|hooks| hooks.get_mut(id))
+            .ok_or(HookError::NotFound)?;
+        hook.enabled = enabled;
+        Ok(())
+    }
+
+    /// Returns whether a registered hook is enabled, see
+    /// [`set_hook_enabled`](Self::set_hook_enabled).
+    ///
+    /// Returns [`None`] if no hook with the given ID is registered.
+    #[must_use]
+    pub fn is_hook_enabled(&self, id: &HookID) -> Option<bool> {
+        self.hooks
+            .get(&id.extension_point_id)?
+            .get(id)
+            .map(BoxedHook::is_enabled)
     }
 
     /// Gets all hooks registered by a specific [Plugin](crate::Plugin).
@@ -1205,7 +3447,13 @@ impl HookRegistry {
     ///
     /// # Returns
     ///
-    /// A vector of references to hooks that match the filter
+    /// A vector of references to hooks that match the filter, sorted by their [`HookID`].
+    ///
+    /// # Ordering
+    ///
+    /// Since every [`HookID`] is unique in the registry, sorting by it gives a deterministic
+    /// order, even for hooks of different [Plugins](crate::Plugin) that have the same name. The
+    /// order is by [`PluginID`] first, then by [`ExtensionPointID`], then by discriminator.
     ///
     /// # Examples
     ///
@@ -1248,6 +3496,11 @@ impl HookRegistry {
     /// });
     ///
     /// assert_eq!(file_loggers.len(), 1);
+    ///
+    /// // The order is always the same, regardless of the registration order
+    /// let all_loggers = registry.get_by_filter(|_| true);
+    /// assert_eq!(all_loggers[0].0, &console_id);
+    /// assert_eq!(all_loggers[1].0, &file_id);
     /// ```
     #[must_use]
     pub fn get_by_filter<F>(&self, f: F) -> Vec<(&HookID, &BoxedHook)>
@@ -1255,7 +3508,7 @@ impl HookRegistry {
         F: FnMut(&(&HookID, &BoxedHook)) -> bool,
     {
         let mut v = self.hooks.values().flatten().filter(f).collect::<Vec<_>>();
-        v.sort();
+        v.sort_unstable_by_key(|(id, _)| *id);
         v
     }
 
@@ -1267,7 +3520,8 @@ impl HookRegistry {
     ///
     /// # Returns
     ///
-    /// A vector of mutable references to hooks that match the filter
+    /// A vector of mutable references to hooks that match the filter, sorted by their [`HookID`].
+    /// See [`get_by_filter`](Self::get_by_filter) for details on the ordering.
     ///
     /// # Examples
     ///
@@ -1304,37 +3558,520 @@ impl HookRegistry {
     /// registry.register(&console_id, console_hook).unwrap();
     /// registry.register(&file_id, file_hook).unwrap();
     ///
-    /// // Get only the file logger
-    /// let file_loggers = registry.get_by_filter_mut(|(id, _)| {
-    ///     id.discriminator.as_deref() == Some("file")
-    /// });
+    /// // Get only the file logger
+    /// let file_loggers = registry.get_by_filter_mut(|(id, _)| {
+    ///     id.discriminator.as_deref() == Some("file")
+    /// });
+    ///
+    /// assert_eq!(file_loggers.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn get_by_filter_mut<F>(&mut self, f: F) -> Vec<(&HookID, &mut BoxedHook)>
+    where
+        F: FnMut(&(&HookID, &mut BoxedHook)) -> bool,
+    {
+        let mut v = self
+            .hooks
+            .values_mut()
+            .flatten()
+            .filter(f)
+            .collect::<Vec<_>>();
+        v.sort_unstable_by_key(|(id, _)| *id);
+        // the hooks could be renamed, see mark_renamed
+        self.name_counts = None;
+        for (id, _hook) in &v {
+            if let Some(order) = self.order.get_mut(&id.extension_point_id) {
+                order.take();
+            }
+        }
+        v
+    }
+
+    /// Gets all hooks for a specific [`ExtensionPoint`] type.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The extension point type
+    ///
+    /// # Returns
+    ///
+    /// A vector of references to all hooks registered for the [`ExtensionPoint`], in the order of
+    /// its [`SortStrategy`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID, ExtensionPoint}};
+    ///
+    /// extension_point!(
+    ///     Validator: ValidatorTrait;
+    ///     fn validate(&self, input: &str) -> bool;
+    /// );
+    ///
+    /// struct LengthValidator;
+    /// impl ValidatorTrait for LengthValidator {
+    ///     fn validate(&self, input: &str) -> bool {
+    ///         input.len() > 0
+    ///     }
+    /// }
+    ///
+    /// struct NumberValidator;
+    /// impl ValidatorTrait for NumberValidator {
+    ///     fn validate(&self, input: &str) -> bool {
+    ///         input.parse::<i32>().is_ok()
+    ///     }
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    ///
+    /// let length_hook = Hook::<Validator>::new(Box::new(LengthValidator), "lenh");
+    /// let length_id = HookID::new("validator_plugin", Validator::id(), Some("length"));
+    ///
+    /// let number_hook = Hook::<Validator>::new(Box::new(NumberValidator), "numh");
+    /// let number_id = HookID::new("validator_plugin", Validator::id(), Some("number"));
+    ///
+    /// registry.register(&length_id, length_hook).unwrap();
+    /// registry.register(&number_id, number_hook).unwrap();
+    ///
+    /// dbg!(&registry);
+    /// let validators: Vec<(&HookID, &Hook<_>)> = registry.get_by_extension_point::<Validator>();
+    /// assert_eq!(validators.len(), 2);
+    /// // if we want to actually know which is
+    /// //which, we can use the name method of the hook to get metadata
+    /// validators.iter().for_each(|(_id,v)|{dbg!(v.name());});
+    ///
+    /// // Test the validators
+    /// // 0 is len, 2 is num
+    /// assert!(validators[0].1.inner().validate("123"));
+    /// assert!(validators[1].1.inner().validate("123"));
+    /// assert!(validators[0].1.inner().validate("abc"));
+    /// assert!(!validators[1].1.inner().validate("abc"));
+    /// ```
+    #[must_use]
+    pub fn get_by_extension_point<E: ExtensionPoint>(&self) -> Vec<(&HookID, &Hook<E>)> {
+        self.iter_by_extension_point::<E>().collect()
+    }
+
+    /// Iterates over all hooks registered for a specific [`ExtensionPoint`] type.
+    ///
+    /// The hooks come in the same order as returned by
+    /// [`get_by_extension_point`](Self::get_by_extension_point), but nothing is collected into a
+    /// [`Vec`]. This is the better choice when only some of the hooks are needed, for example to
+    /// find the first hook that can handle something, or to count hooks that match a condition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     CommandHandler: CommandHandlerTrait;
+    ///     fn can_handle(&self, cmd: &str) -> bool;
+    /// );
+    ///
+    /// struct Prefix(&'static str);
+    /// impl CommandHandlerTrait for Prefix {
+    ///     fn can_handle(&self, cmd: &str) -> bool {
+    ///         cmd.starts_with(self.0)
+    ///     }
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// for (plugin, prefix) in [("git_plugin", "git"), ("cargo_plugin", "cargo")] {
+    ///     let id = HookID::for_ep::<CommandHandler>(plugin, None);
+    ///     registry
+    ///         .register(&id, Hook::<CommandHandler>::new(Box::new(Prefix(prefix)), plugin))
+    ///         .unwrap();
+    /// }
+    ///
+    /// let handler = registry
+    ///     .iter_by_extension_point::<CommandHandler>()
+    ///     .find(|(_id, hook)| hook.inner().can_handle("git status"));
+    /// assert_eq!(handler.unwrap().0.plugin_id, "git_plugin");
+    ///
+    /// assert_eq!(registry.iter_by_extension_point::<CommandHandler>().count(), 2);
+    /// ```
+    pub fn iter_by_extension_point<E: ExtensionPoint>(
+        &self,
+    ) -> impl Iterator<Item = (&HookID, &Hook<E>)> {
+        self.view_by_extension_point::<E>().into_iter()
+    }
+
+    /// Gets all hooks registered for the [`ExtensionPoint`] with the given name.
+    ///
+    /// This is for when the extension point is only known at runtime, for example from a
+    /// configuration file. The name is compared to [`ExtensionPoint::name`], which is the full
+    /// type name of the extension point unless it is overridden. Since the type is not known
+    /// statically, the hooks are returned as [`BoxedHook`]s, in the same order as
+    /// [`get_by_extension_point`](Self::get_by_extension_point) would return them.
+    ///
+    /// Only extension points with at least one hook can be found, and an unknown name gives an
+    /// empty [`Vec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{ExtensionPoint, HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Exporter: ExporterTrait;
+    ///     fn export(&self) -> &'static str;
+    /// );
+    ///
+    /// struct CsvExporter;
+    /// impl ExporterTrait for CsvExporter {
+    ///     fn export(&self) -> &'static str {
+    ///         "a,b,c"
+    ///     }
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// let id = HookID::for_ep::<Exporter>("csv_plugin", None);
+    /// registry.register(&id, Hook::<Exporter>::new(Box::new(CsvExporter), "csv")).unwrap();
+    ///
+    /// // e.g. read from a config file
+    /// let configured = Exporter::name().to_string();
+    ///
+    /// let hooks = registry.get_by_extension_point_name(&configured);
+    /// assert_eq!(hooks.len(), 1);
+    /// assert_eq!(hooks[0].1.name(), "csv");
+    /// assert_eq!(hooks[0].1.downcast::<Exporter>().unwrap().inner().export(), "a,b,c");
+    ///
+    /// assert!(registry.get_by_extension_point_name("no_such_ep").is_empty());
+    /// ```
+    #[must_use]
+    pub fn get_by_extension_point_name(&self, name: &str) -> Vec<(&HookID, &BoxedHook)> {
+        let Some((eid, hooks)) = self.hooks.iter().find(|(_eid, hooks)| {
+            hooks
+                .values()
+                .next()
+                .is_some_and(|hook| hook.ep_name() == name)
+        }) else {
+            return Vec::new();
+        };
+        self.order_of(*eid)
+            .iter()
+            .filter_map(|id| hooks.get_key_value(id))
+            .collect()
+    }
+
+    /// Gets all hooks registered for a specific [`ExtensionPoint`] type, sorted by a key.
+    ///
+    /// This is like [`get_by_extension_point`](Self::get_by_extension_point), but the order is
+    /// decided by the caller instead of the [`SortStrategy`], for example by a value that the hooks
+    /// report at runtime. `key` is called once per hook. Hooks with equal keys keep the order of
+    /// the [`SortStrategy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Source: SourceTrait;
+    ///     fn latency_ms(&self) -> u32;
+    /// );
+    ///
+    /// struct Fixed(u32);
+    /// impl SourceTrait for Fixed {
+    ///     fn latency_ms(&self) -> u32 {
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// for (plugin, latency) in [("cache", 1), ("network", 120), ("disk", 15)] {
+    ///     let id = HookID::for_ep::<Source>(plugin, None);
+    ///     registry.register(&id, Hook::<Source>::new(Box::new(Fixed(latency)), plugin)).unwrap();
+    /// }
+    ///
+    /// let fastest_first = registry.get_by_extension_point_by_key::<Source, _>(|_id, hook| {
+    ///     hook.inner().latency_ms()
+    /// });
+    /// let order: Vec<_> = fastest_first.iter().map(|(id, _hook)| id.plugin_id).collect();
+    /// assert_eq!(order, ["cache", "disk", "network"]);
+    /// ```
+    #[must_use]
+    pub fn get_by_extension_point_by_key<E: ExtensionPoint, K: Ord>(
+        &self,
+        key: impl Fn(&HookID, &Hook<E>) -> K,
+    ) -> Vec<(&HookID, &Hook<E>)> {
+        let mut hooks = self.get_by_extension_point::<E>();
+        // stable, so equal keys keep the order of the sort strategy
+        hooks.sort_by_cached_key(|(id, hook)| key(id, hook));
+        hooks
+    }
+
+    /// Gets all hooks registered for a specific [`ExtensionPoint`] type, without allocating.
+    ///
+    /// This is like [`get_by_extension_point`](Self::get_by_extension_point), but returns a
+    /// [`HookView`] that borrows the registry. The registry keeps the hooks of every extension
+    /// point in the order of its [`SortStrategy`], so neither sorting nor collecting into a
+    /// [`Vec`] is needed. This makes it the cheapest way to run the hooks of an extension point
+    /// repeatedly, like once per frame.
+    ///
+    /// The order is computed by the first call after the hooks of the extension point changed,
+    /// for example by registering, deregistering or renaming hooks, or by setting its strategy.
+    /// Later calls reuse it until the hooks change again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Renderer: RendererTrait;
+    ///     fn render(&self) -> String;
+    /// );
+    ///
+    /// struct Text(&'static str);
+    /// impl RendererTrait for Text {
+    ///     fn render(&self) -> String {
+    ///         self.0.to_string()
+    ///     }
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// for (plugin, text) in [("b_plugin", "world"), ("a_plugin", "hello")] {
+    ///     let id = HookID::for_ep::<Renderer>(plugin, None);
+    ///     registry.register(&id, Hook::<Renderer>::new(Box::new(Text(text)), text)).unwrap();
+    /// }
+    ///
+    /// for _frame in 0..3 {
+    ///     let view = registry.view_by_extension_point::<Renderer>();
+    ///     assert_eq!(view.len(), 2);
+    ///     let frame: Vec<String> = view.iter().map(|(_id, hook)| hook.inner().render()).collect();
+    ///     assert_eq!(frame, ["hello", "world"]);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn view_by_extension_point<E: ExtensionPoint>(&self) -> HookView<'_, E> {
+        let eid = E::id();
+        HookView {
+            ids: self.order_of(eid),
+            hooks: self.hooks.get(&eid),
+            marker: PhantomData,
+        }
+    }
+
+    /// Gets all hooks registered for a specific [`ExtensionPoint`] type, together with their
+    /// rank in the order of execution.
+    ///
+    /// The hooks are in the same order as returned by
+    /// [`get_by_extension_point`](Self::get_by_extension_point), which takes the
+    /// [priority](Hook::with_priority) of the hooks and the [`SortStrategy`] into account. The rank
+    /// is the 0-based position in that order. This is useful for showing the order to a user,
+    /// for example in a list that can be rearranged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Widget: WidgetTrait;
+    /// );
+    ///
+    /// struct Clock;
+    /// impl WidgetTrait for Clock {}
+    ///
+    /// struct Weather;
+    /// impl WidgetTrait for Weather {}
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// registry
+    ///     .register(
+    ///         &HookID::for_ep::<Widget>("a_clock_plugin", None),
+    ///         Hook::<Widget>::new(Box::new(Clock), "clock"),
+    ///     )
+    ///     .unwrap();
+    /// registry
+    ///     .register(
+    ///         &HookID::for_ep::<Widget>("b_weather_plugin", None),
+    ///         Hook::<Widget>::new(Box::new(Weather), "weather").with_priority(5),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let ranked: Vec<(usize, &str, i32)> = registry
+    ///     .ordered_with_rank::<Widget>()
+    ///     .into_iter()
+    ///     .map(|(rank, _id, hook)| (rank, hook.name(), hook.priority()))
+    ///     .collect();
+    /// assert_eq!(ranked, [(0, "weather", 5), (1, "clock", 0)]);
+    /// ```
+    #[must_use]
+    pub fn ordered_with_rank<E: ExtensionPoint>(&self) -> Vec<(usize, &HookID, &Hook<E>)> {
+        self.view_by_extension_point::<E>()
+            .iter()
+            .enumerate()
+            .map(|(rank, (id, hook))| (rank, id, hook))
+            .collect()
+    }
+
+    /// Finds hooks of the [`ExtensionPoint`] `E` that share a [priority](Hook::with_priority).
+    ///
+    /// The order of hooks with the same priority is decided by the [`SortStrategy`] alone, which
+    /// may not be what the plugin authors expect. Each group of hooks with the same priority is
+    /// returned in the order of [`get_by_extension_point`](Self::get_by_extension_point), and
+    /// groups with a single hook are left out. An empty result means that the priorities alone
+    /// decide the order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Filter: FilterTrait;
+    /// );
+    ///
+    /// struct Noop;
+    /// impl FilterTrait for Noop {}
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// for (plugin, priority) in [("spam_plugin", 10), ("b_plugin", 0), ("a_plugin", 0)] {
+    ///     let hook = Hook::<Filter>::new(Box::new(Noop), plugin).with_priority(priority);
+    ///     registry.register(&HookID::for_ep::<Filter>(plugin, None), hook).unwrap();
+    /// }
+    ///
+    /// // both have priority 0, so only their plugin IDs decide the order
+    /// let ties = registry.priority_ties::<Filter>();
+    /// assert_eq!(ties.len(), 1);
+    /// let plugins: Vec<_> = ties[0].iter().map(|id| id.plugin_id).collect();
+    /// assert_eq!(plugins, ["a_plugin", "b_plugin"]);
+    /// ```
+    #[must_use]
+    pub fn priority_ties<E: ExtensionPoint>(&self) -> Vec<Vec<HookID>> {
+        let ordered: Vec<(&HookID, &Hook<E>)> =
+            self.view_by_extension_point::<E>().iter().collect();
+        // the priority is the first sort key, so equal priorities are next to each other
+        ordered
+            .chunk_by(|a, b| a.1.priority() == b.1.priority())
+            .filter(|group| group.len() > 1)
+            .map(|group| group.iter().map(|(id, _hook)| (*id).clone()).collect())
+            .collect()
+    }
+
+    /// Sets the [`SortStrategy`] for the hooks of the [`ExtensionPoint`] `E`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID, SortStrategy}};
+    ///
+    /// extension_point!(
+    ///     Step: StepTrait;
+    /// );
+    ///
+    /// struct Noop;
+    /// impl StepTrait for Noop {}
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// for (plugin, name) in [("b_plugin", "first"), ("a_plugin", "second")] {
+    ///     let id = HookID::for_ep::<Step>(plugin, None);
+    ///     registry.register(&id, Hook::<Step>::new(Box::new(Noop), name)).unwrap();
+    /// }
+    ///
+    /// let names = |registry: &HookRegistry| -> Vec<String> {
+    ///     registry
+    ///         .get_by_extension_point::<Step>()
+    ///         .iter()
+    ///         .map(|(_id, hook)| hook.name().to_string())
+    ///         .collect()
+    /// };
+    ///
+    /// // by default, hooks are sorted by HookID
+    /// assert_eq!(names(&registry), ["second", "first"]);
+    ///
+    /// registry.set_sort_strategy::<Step>(SortStrategy::Insertion);
+    /// assert_eq!(registry.sort_strategy::<Step>(), SortStrategy::Insertion);
+    /// assert_eq!(names(&registry), ["first", "second"]);
+    /// ```
+    pub fn set_sort_strategy<E: ExtensionPoint>(&mut self, strategy: SortStrategy) {
+        self.sort_strategies.insert(E::id(), strategy);
+        self.mark_order_stale(E::id());
+    }
+
+    /// Returns the [`SortStrategy`] for the hooks of the [`ExtensionPoint`] `E`.
+    ///
+    /// This is [`SortStrategy::Id`] unless changed with
+    /// [`set_sort_strategy`](Self::set_sort_strategy).
+    #[must_use]
+    pub fn sort_strategy<E: ExtensionPoint>(&self) -> SortStrategy {
+        self.sort_strategies
+            .get(&E::id())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Gets the hooks of a specific [Plugin](crate::Plugin) for a specific [`ExtensionPoint`]
+    /// type.
+    ///
+    /// Unlike filtering the result of [`get_by_plugin`](Self::get_by_plugin), this only looks at
+    /// the hooks of the extension point, and returns typed hooks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::HookRegistry, register_hook};
+    ///
+    /// extension_point!(
+    ///     Formatter: FormatterTrait;
+    ///     fn format(&self, input: &str) -> String;
+    /// );
+    ///
+    /// extension_point!(
+    ///     Parser: ParserTrait;
+    ///     fn parse(&self, input: &str) -> bool;
+    /// );
+    ///
+    /// struct Upper;
+    /// impl FormatterTrait for Upper {
+    ///     fn format(&self, input: &str) -> String {
+    ///         input.to_uppercase()
+    ///     }
+    /// }
+    ///
+    /// struct Anything;
+    /// impl ParserTrait for Anything {
+    ///     fn parse(&self, _input: &str) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// register_hook!(registry, "text_plugin", Formatter, Upper);
+    /// register_hook!(registry, "text_plugin", Parser, Anything);
+    /// register_hook!(registry, "other_plugin", Formatter, Upper);
     ///
-    /// assert_eq!(file_loggers.len(), 1);
+    /// let formatters = registry.get_by_plugin_and_ep::<Formatter>("text_plugin");
+    /// assert_eq!(formatters.len(), 1);
+    /// assert_eq!(formatters[0].1.inner().format("hi"), "HI");
     /// ```
     #[must_use]
-    pub fn get_by_filter_mut<F>(&mut self, f: F) -> Vec<(&HookID, &mut BoxedHook)>
-    where
-        F: FnMut(&(&HookID, &mut BoxedHook)) -> bool,
-    {
-        let mut v = self
-            .hooks
-            .values_mut()
-            .flatten()
-            .filter(f)
-            .collect::<Vec<_>>();
-        v.sort();
-        v
+    pub fn get_by_plugin_and_ep<E: ExtensionPoint>(
+        &self,
+        plugin_id: PluginID,
+    ) -> Vec<(&HookID, &Hook<E>)> {
+        self.view_by_extension_point::<E>()
+            .iter()
+            .filter(|(id, _hook)| id.plugin_id == plugin_id)
+            .collect()
     }
 
-    /// Gets all hooks for a specific [`ExtensionPoint`] type.
-    ///
-    /// # Type Parameters
+    /// Gets any hook for a specific [`ExtensionPoint`] type.
     ///
-    /// - `E`: The extension point type
+    /// This is for cases where any implementation of the extension point will do. The returned
+    /// hook is the first in the order of [`get_by_extension_point`](Self::get_by_extension_point),
+    /// but this does not need to collect and sort all hooks.
     ///
     /// # Returns
     ///
-    /// A vector of references to all hooks registered for the [`ExtensionPoint`]
+    /// - `Some(HookOrDefault::Registered)` for the first hook registered for the
+    ///   [`ExtensionPoint`]
+    /// - `Some(HookOrDefault::Default)` if no hook is registered, but the [`ExtensionPoint`] has a
+    ///   [default hook](ExtensionPoint::default_hook)
+    /// - `None` otherwise
     ///
     /// # Examples
     ///
@@ -1342,60 +4079,32 @@ impl HookRegistry {
     /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID, ExtensionPoint}};
     ///
     /// extension_point!(
-    ///     Validator: ValidatorTrait;
-    ///     fn validate(&self, input: &str) -> bool;
+    ///     Clock: ClockTrait;
+    ///     fn now(&self) -> u64;
     /// );
     ///
-    /// struct LengthValidator;
-    /// impl ValidatorTrait for LengthValidator {
-    ///     fn validate(&self, input: &str) -> bool {
-    ///         input.len() > 0
-    ///     }
-    /// }
-    ///
-    /// struct NumberValidator;
-    /// impl ValidatorTrait for NumberValidator {
-    ///     fn validate(&self, input: &str) -> bool {
-    ///         input.parse::<i32>().is_ok()
+    /// struct FixedClock;
+    /// impl ClockTrait for FixedClock {
+    ///     fn now(&self) -> u64 {
+    ///         1337
     ///     }
     /// }
     ///
     /// let mut registry = HookRegistry::new();
+    /// assert!(registry.get_any::<Clock>().is_none());
     ///
-    /// let length_hook = Hook::<Validator>::new(Box::new(LengthValidator), "lenh");
-    /// let length_id = HookID::new("validator_plugin", Validator::id(), Some("length"));
-    ///
-    /// let number_hook = Hook::<Validator>::new(Box::new(NumberValidator), "numh");
-    /// let number_id = HookID::new("validator_plugin", Validator::id(), Some("number"));
-    ///
-    /// registry.register(&length_id, length_hook).unwrap();
-    /// registry.register(&number_id, number_hook).unwrap();
-    ///
-    /// dbg!(&registry);
-    /// let validators: Vec<(&HookID, &Hook<_>)> = registry.get_by_extension_point::<Validator>();
-    /// assert_eq!(validators.len(), 2);
-    /// // if we want to actually know which is
-    /// //which, we can use the name method of the hook to get metadata
-    /// validators.iter().for_each(|(_id,v)|{dbg!(v.name());});
+    /// let id = HookID::new("clock_plugin", Clock::id(), None);
+    /// registry.register(&id, Hook::<Clock>::new(Box::new(FixedClock), "fixed")).unwrap();
     ///
-    /// // Test the validators
-    /// // 0 is len, 2 is num
-    /// assert!(validators[0].1.inner().validate("123"));
-    /// assert!(validators[1].1.inner().validate("123"));
-    /// assert!(validators[0].1.inner().validate("abc"));
-    /// assert!(!validators[1].1.inner().validate("abc"));
+    /// let clock = registry.get_any::<Clock>().unwrap();
+    /// assert_eq!(clock.inner().now(), 1337);
     /// ```
     #[must_use]
-    pub fn get_by_extension_point<E: ExtensionPoint>(&self) -> Vec<(&HookID, &Hook<E>)> {
-        let Some(boxed_hooks) = self.hooks.get(&E::id()) else {
-            return Vec::new();
-        };
-        let mut v: Vec<(&HookID, &Hook<E>)> = boxed_hooks
-            .iter()
-            .filter_map(|(k, v)| v.downcast().map(|hook| (k, hook)))
-            .collect();
-        v.sort();
-        v
+    pub fn get_any<E: ExtensionPoint>(&self) -> Option<HookOrDefault<'_, E>> {
+        self.view_by_extension_point::<E>()
+            .get(0)
+            .map(|(_id, hook)| HookOrDefault::Registered(hook))
+            .or_else(|| E::default_hook().map(HookOrDefault::Default))
     }
 
     /// Gets all mutable hooks for a specific [`ExtensionPoint`] type.
@@ -1406,7 +4115,10 @@ impl HookRegistry {
     ///
     /// # Returns
     ///
-    /// A vector of mutable references to all hooks registered for the [`ExtensionPoint`]
+    /// A vector of mutable references to all hooks registered for the [`ExtensionPoint`], in the
+    /// order of its [`SortStrategy`]. [Shared](Hook#ownership) hooks that are still referenced
+    /// elsewhere are left out, see
+    /// [`iter_by_extension_point_mut`](Self::iter_by_extension_point_mut).
     ///
     /// # Examples
     ///
@@ -1461,15 +4173,67 @@ impl HookRegistry {
     pub fn get_by_extension_point_mut<E: ExtensionPoint>(
         &mut self,
     ) -> Vec<(&HookID, &mut Hook<E>)> {
-        let Some(boxed_hooks) = self.hooks.get_mut(&E::id()) else {
-            return Vec::new();
-        };
-        let mut v: Vec<(&HookID, &mut Hook<E>)> = boxed_hooks
-            .iter_mut()
-            .filter_map(|(k, v)| v.downcast_mut().map(|hook| (k, hook)))
-            .collect();
-        v.sort();
-        v
+        self.iter_by_extension_point_mut::<E>().collect()
+    }
+
+    /// Iterates mutably over all hooks registered for a specific [`ExtensionPoint`] type.
+    ///
+    /// The hooks come in the same order as returned by
+    /// [`get_by_extension_point_mut`](Self::get_by_extension_point_mut). Unlike
+    /// [`iter_by_extension_point`](Self::iter_by_extension_point), this can not use the order
+    /// remembered by the registry, so the hooks are sorted into a temporary buffer first.
+    ///
+    /// [Shared](Hook#ownership) hooks whose implementation is still referenced elsewhere can not
+    /// be accessed mutably and are skipped, so [`Hook::inner_mut`] can be used on every hook.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::{HookRegistry, Hook, HookID}};
+    ///
+    /// extension_point!(
+    ///     Counter: CounterTrait;
+    ///     fn bump(&mut self) -> u32;
+    /// );
+    ///
+    /// struct Count(u32);
+    /// impl CounterTrait for Count {
+    ///     fn bump(&mut self) -> u32 {
+    ///         self.0 += 1;
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// for (plugin, start) in [("b_plugin", 10), ("a_plugin", 0)] {
+    ///     let id = HookID::for_ep::<Counter>(plugin, None);
+    ///     registry.register(&id, Hook::<Counter>::new(Box::new(Count(start)), plugin)).unwrap();
+    /// }
+    ///
+    /// // bump only the first counter, in the order of the sort strategy
+    /// let (id, hook) = registry.iter_by_extension_point_mut::<Counter>().next().unwrap();
+    /// assert_eq!(id.plugin_id, "a_plugin");
+    /// assert_eq!(hook.inner_mut().bump(), 1);
+    /// ```
+    pub fn iter_by_extension_point_mut<E: ExtensionPoint>(
+        &mut self,
+    ) -> impl Iterator<Item = (&HookID, &mut Hook<E>)> {
+        let strategy = self.sort_strategy::<E>();
+        self.mark_renamed(E::id());
+        let mut v: Vec<(&HookID, &mut BoxedHook)> = self
+            .hooks
+            .get_mut(&E::id())
+            .map(|boxed_hooks| boxed_hooks.iter_mut().collect())
+            .unwrap_or_default();
+        v.sort_by(|a, b| strategy.compare((a.0, &*a.1), (b.0, &*b.1)));
+        v.into_iter().filter_map(|(k, v)| {
+            let hook: &mut Hook<E> = v.downcast_mut()?;
+            if hook.try_inner_mut().is_none() {
+                debug!("Skipping hook {k:?}, its implementation is still shared");
+                return None;
+            }
+            Some((k, hook))
+        })
     }
 
     /// Deregisters all hooks for a specific [Plugin](crate::Plugin).
@@ -1542,6 +4306,242 @@ impl HookRegistry {
                 .expect("could not deregister a hook that we know exists");
         }
     }
+
+    /// Deregisters all hooks.
+    ///
+    /// Settings of the registry, like the [`SortStrategy`] of each extension point and the
+    /// callbacks of [`on_register_for`](Self::on_register_for), are kept. The callbacks are called
+    /// for every removed hook.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::HookRegistry, register_hook};
+    ///
+    /// extension_point!(
+    ///     Handler: HandlerTrait;
+    /// );
+    ///
+    /// struct Noop;
+    /// impl HandlerTrait for Noop {}
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// register_hook!(registry, "plugin1", Handler, Noop);
+    /// register_hook!(registry, "plugin2", Handler, Noop);
+    ///
+    /// registry.clear();
+    /// assert_eq!(registry.stats().total_hooks, 0);
+    /// ```
+    pub fn clear(&mut self) {
+        let to_del: Vec<HookID> = self
+            .hooks
+            .values()
+            .flat_map(HashMap::keys)
+            .cloned()
+            .collect();
+
+        for id in to_del {
+            self.deregister(&id);
+        }
+    }
+
+    /// Marks a plugin as gone, and deregisters all of its hooks.
+    ///
+    /// A [`PluginManager`](crate::PluginManager) removes the hooks of plugins it unloads by
+    /// itself. When a registry is shared by other means, for example with
+    /// [`PluginManager::with_registry`](crate::PluginManager::with_registry), whoever notices
+    /// that a plugin went away can call this to make sure no orphaned hooks of it are left
+    /// behind, without needing access to a manager.
+    ///
+    /// The registry remembers which plugins are gone, see
+    /// [`is_plugin_gone`](Self::is_plugin_gone). Registering a new hook for a plugin that is gone
+    /// marks it as alive again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, hook::HookRegistry, register_hook};
+    ///
+    /// extension_point!(
+    ///     Handler: HandlerTrait;
+    ///     fn handle(&self, input: &str) -> String;
+    /// );
+    ///
+    /// struct EchoHandler;
+    /// impl HandlerTrait for EchoHandler {
+    ///     fn handle(&self, input: &str) -> String {
+    ///         input.to_string()
+    ///     }
+    /// }
+    ///
+    /// let mut registry = HookRegistry::new();
+    /// register_hook!(registry, "echo_plugin", Handler, EchoHandler);
+    ///
+    /// registry.mark_plugin_gone("echo_plugin");
+    /// assert!(registry.is_plugin_gone("echo_plugin"));
+    /// assert!(registry.get_by_plugin("echo_plugin").is_empty());
+    ///
+    /// // registering again revives the plugin
+    /// register_hook!(registry, "echo_plugin", Handler, EchoHandler);
+    /// assert!(!registry.is_plugin_gone("echo_plugin"));
+    /// ```
+    pub fn mark_plugin_gone(&mut self, plugin_id: PluginID) {
+        self.deregister_hooks_for_plugin(plugin_id);
+        self.gone_plugins.insert(plugin_id);
+    }
+
+    /// Checks if a plugin was marked as gone with [`mark_plugin_gone`](Self::mark_plugin_gone),
+    /// and did not register any hooks since.
+    ///
+    /// # Examples
+    ///
+    /// See [`mark_plugin_gone`](Self::mark_plugin_gone).
+    #[must_use]
+    pub fn is_plugin_gone(&self, plugin_id: PluginID) -> bool {
+        self.gone_plugins.contains(plugin_id)
+    }
+
+    /// Creates a [`ScopedRegistry`] view of this registry for the plugin with the given ID.
+    ///
+    /// # Examples
+    ///
+    /// See [`ScopedRegistry`].
+    #[must_use]
+    pub fn scoped(&self, plugin_id: PluginID) -> ScopedRegistry<'_> {
+        ScopedRegistry::new(self, plugin_id)
+    }
+}
+
+/// A read-only view of a [`HookRegistry`] that only shows the hooks of a single plugin.
+///
+/// All reads through a [`ScopedRegistry`] only see the hooks registered by the plugin the view is
+/// bound to, plus the hooks of other plugins that were explicitly shared with it using
+/// [`with_shared`](Self::with_shared). This is meant for sandboxed plugin models, where a plugin
+/// should not be able to look at the hooks of other plugins.
+///
+/// # Examples
+///
+/// ```
+/// use steckrs::{extension_point, hook::{HookID, HookRegistry}, register_hook};
+///
+/// extension_point!(
+///     Storage: StorageTrait;
+///     fn get(&self, key: &str) -> Option<String>;
+/// );
+///
+/// struct EmptyStorage;
+/// impl StorageTrait for EmptyStorage {
+///     fn get(&self, _key: &str) -> Option<String> {
+///         None
+///     }
+/// }
+///
+/// let mut registry = HookRegistry::new();
+/// register_hook!(registry, "plugin_a", Storage, EmptyStorage);
+/// register_hook!(registry, "plugin_b", Storage, EmptyStorage);
+/// register_hook!(registry, "plugin_c", Storage, EmptyStorage);
+///
+/// // plugin_a only sees its own hooks
+/// let scoped = registry.scoped("plugin_a");
+/// assert_eq!(scoped.get_by_extension_point::<Storage>().len(), 1);
+/// assert!(!scoped.exists(&HookID::for_ep::<Storage>("plugin_b", None)));
+///
+/// // unless another hook is shared with it
+/// let shared = HookID::for_ep::<Storage>("plugin_b", None);
+/// let scoped = registry.scoped("plugin_a").with_shared([shared.clone()]);
+/// assert_eq!(scoped.get_by_extension_point::<Storage>().len(), 2);
+/// assert!(scoped.get::<Storage>(&shared).is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScopedRegistry<'a> {
+    registry: &'a HookRegistry,
+    plugin_id: PluginID,
+    shared: Vec<HookID>,
+}
+
+impl<'a> ScopedRegistry<'a> {
+    /// Creates a new view of `registry` that only shows the hooks of the plugin with the given ID.
+    ///
+    /// This is the same as [`HookRegistry::scoped`].
+    #[must_use]
+    pub fn new(registry: &'a HookRegistry, plugin_id: PluginID) -> Self {
+        Self {
+            registry,
+            plugin_id,
+            shared: Vec::new(),
+        }
+    }
+
+    /// Makes the hooks with the given [`HookID`]s visible through this view, even though they
+    /// belong to other plugins.
+    ///
+    /// # Examples
+    ///
+    /// See [`ScopedRegistry`].
+    #[must_use]
+    pub fn with_shared(mut self, shared: impl IntoIterator<Item = HookID>) -> Self {
+        self.shared.extend(shared);
+        self
+    }
+
+    /// Returns the ID of the plugin this view is bound to.
+    #[must_use]
+    pub fn plugin_id(&self) -> PluginID {
+        self.plugin_id
+    }
+
+    /// Checks if the hook with the given [`HookID`] may be seen through this view.
+    ///
+    /// This does not check whether the hook actually exists, see [`exists`](Self::exists).
+    #[must_use]
+    pub fn is_visible(&self, id: &HookID) -> bool {
+        id.plugin_id == self.plugin_id || self.shared.contains(id)
+    }
+
+    /// Checks if a visible hook with the given [`HookID`] exists.
+    ///
+    /// Like [`HookRegistry::exists`], but hooks that are not [visible](Self::is_visible) count as
+    /// missing.
+    #[must_use]
+    pub fn exists(&self, id: &HookID) -> bool {
+        self.is_visible(id) && self.registry.exists(id)
+    }
+
+    /// Gets a visible hook by [`HookID`].
+    ///
+    /// Like [`HookRegistry::get`], but hooks that are not [visible](Self::is_visible) count as
+    /// missing.
+    #[must_use]
+    pub fn get<E: ExtensionPoint>(&self, id: &HookID) -> Option<&'a Hook<E>> {
+        if !self.is_visible(id) {
+            return None;
+        }
+        self.registry.get(id)
+    }
+
+    /// Gets all visible hooks for a specific [`ExtensionPoint`] type.
+    ///
+    /// Like [`HookRegistry::get_by_extension_point`], but only returns hooks that are
+    /// [visible](Self::is_visible).
+    #[must_use]
+    pub fn get_by_extension_point<E: ExtensionPoint>(&self) -> Vec<(&'a HookID, &'a Hook<E>)> {
+        let mut hooks = self.registry.get_by_extension_point::<E>();
+        hooks.retain(|(id, _hook)| self.is_visible(id));
+        hooks
+    }
+
+    /// Gets all visible hooks matching a filter function.
+    ///
+    /// Like [`HookRegistry::get_by_filter`], but only returns hooks that are
+    /// [visible](Self::is_visible).
+    #[must_use]
+    pub fn get_by_filter<F>(&self, mut f: F) -> Vec<(&'a HookID, &'a BoxedHook)>
+    where
+        F: FnMut(&(&HookID, &BoxedHook)) -> bool,
+    {
+        self.registry
+            .get_by_filter(|entry| self.is_visible(entry.0) && f(entry))
+    }
 }
 
 #[cfg(test)]
@@ -1549,6 +4549,137 @@ mod tests {
     use super::*;
     use crate::*;
 
+    #[test]
+    fn test_filter_order_is_deterministic() {
+        extension_point!(Greeter: GreeterTrait;);
+
+        struct Hello;
+        impl GreeterTrait for Hello {}
+
+        let plugins = ["charlie", "alpha", "delta", "bravo"];
+        let mut forward = HookRegistry::new();
+        let mut backward = HookRegistry::new();
+        for plugin in plugins {
+            forward
+                .register(
+                    &HookID::new(plugin, Greeter::id(), None),
+                    Hook::<Greeter>::new(Box::new(Hello), "same_name"),
+                )
+                .unwrap();
+        }
+        for plugin in plugins.iter().rev() {
+            backward
+                .register(
+                    &HookID::new(plugin, Greeter::id(), None),
+                    Hook::<Greeter>::new(Box::new(Hello), "same_name"),
+                )
+                .unwrap();
+        }
+
+        let order = |registry: &HookRegistry| -> Vec<PluginID> {
+            registry
+                .get_by_filter(|_| true)
+                .into_iter()
+                .map(|(id, _)| id.plugin_id)
+                .collect()
+        };
+        assert_eq!(order(&forward), vec!["alpha", "bravo", "charlie", "delta"]);
+        assert_eq!(order(&forward), order(&backward));
+    }
+
+    #[test]
+    fn test_view_order_follows_renames() {
+        extension_point!(Greeter: GreeterTrait;);
+
+        struct Hello;
+        impl GreeterTrait for Hello {}
+
+        let mut registry = HookRegistry::new();
+        registry.set_sort_strategy::<Greeter>(SortStrategy::Name);
+        for (plugin, name) in [("alpha", "b"), ("bravo", "c"), ("charlie", "a")] {
+            registry
+                .register(
+                    &HookID::new(plugin, Greeter::id(), None),
+                    Hook::<Greeter>::new(Box::new(Hello), name),
+                )
+                .unwrap();
+        }
+
+        let order = |registry: &HookRegistry| -> Vec<PluginID> {
+            registry
+                .view_by_extension_point::<Greeter>()
+                .iter()
+                .map(|(id, _)| id.plugin_id)
+                .collect()
+        };
+        assert_eq!(order(&registry), vec!["charlie", "alpha", "bravo"]);
+
+        // renamed behind the back of the registry
+        let (_id, hook) = registry
+            .get_by_id_mut(&HookID::new("bravo", Greeter::id(), None))
+            .unwrap();
+        hook.set_name("0");
+        assert_eq!(order(&registry), vec!["bravo", "charlie", "alpha"]);
+
+        // the typed hook and the boxed hook share the name
+        let alpha = HookID::new("alpha", Greeter::id(), None);
+        registry.get_mut::<Greeter>(&alpha).unwrap().set_name("00");
+        assert_eq!(registry.get_by_id(&alpha).unwrap().1.name(), "00");
+        assert_eq!(order(&registry), vec!["bravo", "alpha", "charlie"]);
+
+        registry.deregister(&HookID::new("charlie", Greeter::id(), None));
+        assert_eq!(order(&registry), vec!["bravo", "alpha"]);
+        assert_eq!(registry.get_by_extension_point::<Greeter>().len(), 2);
+    }
+
+    #[test]
+    fn test_integrity_detects_duplicate_names() {
+        trait NoopTrait: Send + Sync {}
+        struct Noop;
+        impl NoopTrait for Noop {}
+
+        #[derive(Debug, Ord, Eq, PartialOrd, PartialEq)]
+        struct First;
+        impl ExtensionPoint for First {
+            type HookTrait = dyn NoopTrait;
+            fn name() -> &'static str {
+                "shared"
+            }
+        }
+
+        #[derive(Debug, Ord, Eq, PartialOrd, PartialEq)]
+        struct Second;
+        impl ExtensionPoint for Second {
+            type HookTrait = dyn NoopTrait;
+            fn name() -> &'static str {
+                "shared"
+            }
+        }
+
+        let mut registry = HookRegistry::new();
+        registry
+            .register(
+                &HookID::for_ep::<First>("plugin", None),
+                Hook::<First>::new(Box::new(Noop), "first"),
+            )
+            .unwrap();
+        assert!(registry.validate_integrity().is_ok());
+
+        registry
+            .register(
+                &HookID::for_ep::<Second>("plugin", None),
+                Hook::<Second>::new(Box::new(Noop), "second"),
+            )
+            .unwrap();
+        assert_eq!(
+            registry.validate_integrity().unwrap_err(),
+            vec![IntegrityIssue::DuplicateName {
+                name: "shared",
+                count: 2
+            }]
+        );
+    }
+
     #[test]
     fn test_hook_with_owned_plugin_id() {
         extension_point!(
@@ -1564,7 +4695,45 @@ mod tests {
         }
 
         let id = PluginIDOwned::from("foo");
-        let hook = Hook::<Validator>::new(Box::new(LengthValidator), id.into());
+        let hook = Hook::<Validator>::new(Box::new(LengthValidator), PluginID::from(id));
         assert!(hook.inner().validate("this is long enough"));
     }
+
+    #[test]
+    fn test_mut_access_skips_shared_hooks_in_use() {
+        extension_point!(
+            Counter: CounterTrait;
+            fn bump(&mut self);
+        );
+
+        struct Count;
+        impl CounterTrait for Count {
+            fn bump(&mut self) {}
+        }
+
+        let mut registry = HookRegistry::new();
+        let shared: Arc<dyn CounterTrait> = Arc::new(Count);
+        registry
+            .register_shared::<Counter>(
+                &HookID::for_ep::<Counter>("shared_plugin", None),
+                shared.clone(),
+                "shared",
+            )
+            .unwrap();
+        registry
+            .register(
+                &HookID::for_ep::<Counter>("owned_plugin", None),
+                Hook::<Counter>::new(Box::new(Count), "owned"),
+            )
+            .unwrap();
+
+        let hooks = registry.get_by_extension_point_mut::<Counter>();
+        assert_eq!(hooks.len(), 1);
+        for (_id, hook) in hooks {
+            hook.inner_mut().bump();
+        }
+
+        drop(shared);
+        assert_eq!(registry.get_by_extension_point_mut::<Counter>().len(), 2);
+    }
 }
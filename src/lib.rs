@@ -42,6 +42,12 @@
 //!
 //! This library logs certain events with the [`tracing`] library.
 //!
+//! Loading, unloading, enabling and disabling a plugin happens inside an `info` span with the
+//! name of the operation and a `plugin_id` field. Events emitted by the plugin itself while it is
+//! loaded or unloaded, like in [`Plugin::on_load`], belong to that span, so all activity of a
+//! plugin can be filtered by its ID. Registering and deregistering hooks is logged at the `debug`
+//! level, with the parts of the [`HookID`](hook::HookID).
+//!
 //! ## Usage Example
 //!
 //! Here's a simple example of how to use steckrs to create a plugin-enabled application:
@@ -113,8 +119,9 @@
 #![warn(clippy::empty_docs)]
 
 use std::any::Any;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
+use std::sync::{Mutex, PoisonError};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -122,10 +129,14 @@ use serde::{Deserialize, Serialize};
 pub mod error;
 pub mod hook;
 pub mod macros;
+pub mod messaging;
+pub mod pipeline;
+pub mod testing;
+pub mod util;
 
-use tracing::{error, warn};
+use tracing::{error, info_span, warn};
 
-use self::error::{PluginError, PluginResult};
+use self::error::{BulkError, PluginError, PluginResult};
 use self::hook::{ExtensionPoint, HookRegistry};
 
 /// Plugin identifier type.
@@ -253,6 +264,179 @@ impl<'de> Deserialize<'de> for PluginIDOwned {
     }
 }
 
+/// Health status reported by a [`Plugin`].
+///
+/// The [`PluginManager`] checks the [`status`](Plugin::status) of a plugin after
+/// [`on_load`](Plugin::on_load) returned successfully. A plugin that reports
+/// [`PluginStatus::Failed`] at that point is treated as if loading had failed.
+///
+/// # Examples
+///
+/// ```
+/// use steckrs::PluginStatus;
+///
+/// assert_eq!(PluginStatus::default(), PluginStatus::Ok);
+/// assert!(PluginStatus::Failed("no config".into()).is_failed());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PluginStatus {
+    /// The plugin is functional
+    #[default]
+    Ok,
+    /// The plugin is loaded, but not functional, with a reason why
+    Failed(String),
+}
+
+impl PluginStatus {
+    /// Returns `true` if this is [`PluginStatus::Failed`].
+    #[inline]
+    #[must_use]
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Self::Failed(_))
+    }
+}
+
+/// Lifecycle state of a [`Plugin`] as known by a [`PluginManager`].
+///
+/// See [`PluginManager::plugin_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PluginState {
+    /// The plugin is not loaded
+    Unloaded,
+    /// The plugin is loaded, but disabled
+    Loaded,
+    /// The plugin is loaded and enabled
+    Enabled,
+    /// The plugin is loaded, but reports [`PluginStatus::Failed`], or the last attempt to load it
+    /// failed (see [`PluginManager::failed_loads`])
+    Failed,
+}
+
+/// Host state handed to a [`Plugin`] during its lifecycle methods.
+///
+/// The [`PluginManager`] passes a [`PluginContext`] to
+/// [`register_hooks_ctx`](Plugin::register_hooks_ctx), [`on_load_ctx`](Plugin::on_load_ctx) and
+/// [`on_unload_ctx`](Plugin::on_unload_ctx), so that plugins can use the [`HookRegistry`] while
+/// initializing or cleaning up, without needing global state. The context also carries the
+/// [features](PluginManager::set_plugin_feature) the host enabled for the plugin.
+///
+/// # Examples
+///
+/// ```
+/// use steckrs::{Plugin, PluginContext, PluginManager, error::PluginResult, hook::HookRegistry};
+///
+/// #[derive(Debug, Default)]
+/// struct CountingPlugin {
+///     enabled: bool,
+///     own_hooks: usize,
+/// }
+///
+/// impl Plugin for CountingPlugin {
+///     fn id(&self) -> steckrs::PluginID {
+///         "counting_plugin"
+///     }
+///
+///     fn description(&self) -> &str {
+///         "Counts its own hooks when it is loaded"
+///     }
+///
+///     fn is_enabled(&self) -> bool {
+///         self.enabled
+///     }
+///
+///     fn enable(&mut self) {
+///         self.enabled = true;
+///     }
+///
+///     fn disable(&mut self) {
+///         self.enabled = false;
+///     }
+///
+///     fn register_hooks(&self, _registry: &mut HookRegistry) -> PluginResult<()> {
+///         Ok(())
+///     }
+///
+///     fn on_load_ctx(&mut self, ctx: &mut PluginContext) -> PluginResult<()> {
+///         assert_eq!(ctx.plugin_id(), "counting_plugin");
+///         self.own_hooks = ctx.registry().get_by_plugin(ctx.plugin_id()).len();
+///         Ok(())
+///     }
+/// }
+///
+/// let mut manager = PluginManager::new();
+/// manager.load_plugin(Box::new(CountingPlugin::default())).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct PluginContext<'a> {
+    plugin_id: PluginID,
+    registry: &'a mut HookRegistry,
+    features: Option<&'a HashSet<String>>,
+}
+
+impl<'a> PluginContext<'a> {
+    /// Creates a new [`PluginContext`] for the plugin with the given ID.
+    ///
+    /// This is mostly useful for calling the lifecycle methods of a [`Plugin`] manually, the
+    /// [`PluginManager`] creates the context itself.
+    #[must_use]
+    pub fn new(plugin_id: PluginID, registry: &'a mut HookRegistry) -> Self {
+        Self {
+            plugin_id,
+            registry,
+            features: None,
+        }
+    }
+
+    /// Sets the features that are enabled for the plugin, see
+    /// [`feature_enabled`](Self::feature_enabled).
+    ///
+    /// A context created with [`new`](Self::new) has no enabled features.
+    #[must_use]
+    pub fn with_features(mut self, features: &'a HashSet<String>) -> Self {
+        self.features = Some(features);
+        self
+    }
+
+    /// Checks if the host enabled a feature for the plugin, see
+    /// [`PluginManager::set_plugin_feature`].
+    ///
+    /// Features are just names, what they mean is up to the plugin. A typical use is to only
+    /// register some hooks in [`register_hooks_ctx`](Plugin::register_hooks_ctx) if a feature is
+    /// enabled.
+    #[must_use]
+    pub fn feature_enabled(&self, feature: &str) -> bool {
+        self.features
+            .is_some_and(|features| features.contains(feature))
+    }
+
+    /// Returns the ID of the plugin this context was created for.
+    #[must_use]
+    pub fn plugin_id(&self) -> PluginID {
+        self.plugin_id
+    }
+
+    /// Returns a reference to the [`HookRegistry`] of the host.
+    #[must_use]
+    pub fn registry(&self) -> &HookRegistry {
+        self.registry
+    }
+
+    /// Returns a mutable reference to the [`HookRegistry`] of the host.
+    #[must_use]
+    pub fn registry_mut(&mut self) -> &mut HookRegistry {
+        self.registry
+    }
+
+    /// Returns a [`ScopedRegistry`](hook::ScopedRegistry) that only shows the hooks of the plugin
+    /// this context was created for.
+    #[must_use]
+    pub fn scoped_registry(&self) -> hook::ScopedRegistry<'_> {
+        self.registry.scoped(self.plugin_id)
+    }
+}
+
 /// Plugin trait that must be implemented by all plugins.
 ///
 /// This trait defines the interface for plugin lifecycle management,
@@ -378,201 +562,280 @@ pub trait Plugin: Any + Send + Sync + Debug {
     fn on_unload(&mut self) -> PluginResult<()> {
         Ok(())
     }
-}
-
-/// Manages plugin loading, execution, and lifecycle.
-///
-/// The [`PluginManager`] is the core component of the steckrs plugin system,
-/// responsible for:
-/// - Loading and unloading plugins
-/// - Enabling and disabling plugins
-/// - Maintaining the hook registry
-/// - Tracking loaded plugins
-///
-/// # Examples
-///
-/// ```
-/// use steckrs::{PluginManager, simple_plugin, extension_point};
-///
-/// // Define extension point
-/// extension_point!(
-///     ExampleExt: ExampleTrait;
-///     fn do_something(&self) -> &'static str;
-/// );
-///
-/// // Define plugin
-/// simple_plugin!(
-///     ExamplePlugin,
-///     "example_plugin",
-///     "An example plugin",
-///     hooks: [(ExampleExt, ExampleHook)]
-/// );
-///
-/// // Hook implementation
-/// struct ExampleHook;
-/// impl ExampleTrait for ExampleHook {
-///     fn do_something(&self) -> &'static str {
-///         "I did something!"
-///     }
-/// }
-///
-/// // Plugin management
-/// let mut manager = PluginManager::new();
-/// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
-/// manager.enable_plugin(ExamplePlugin::ID).unwrap();
-///
-/// // Use plugin hooks
-/// // Get all enabled hooks (plugins could be disabled)
-/// let hooks = manager.get_enabled_hooks_by_ep::<ExampleExt>();
-/// for (_id, hook) in hooks {
-///     assert_eq!(hook.inner().do_something(), "I did something!");
-/// }
-/// ```
-#[derive(Debug, Default)]
-pub struct PluginManager {
-    plugins: HashMap<PluginID, Box<dyn Plugin>>,
-    hook_registry: HookRegistry,
-}
 
-impl PluginManager {
-    /// Creates a new empty plugin manager.
+    /// Registers this plugin's [Hooks](crate::hook::Hook), with access to the host through a
+    /// [`PluginContext`].
+    ///
+    /// This is what the [`PluginManager`] actually calls. The default implementation calls
+    /// [`register_hooks`](Plugin::register_hooks) with the registry of the context. Implement
+    /// this instead to register hooks depending on the
+    /// [features](PluginContext::feature_enabled) that the host enabled for the plugin.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError`] if hook registration fails.
     ///
     /// # Examples
     ///
     /// ```
-    /// use steckrs::PluginManager;
+    /// use steckrs::{
+    ///     extension_point, Plugin, PluginContext, PluginManager,
+    ///     error::PluginResult,
+    ///     hook::{Hook, HookID, HookRegistry},
+    /// };
     ///
-    /// let manager = PluginManager::new();
-    /// assert_eq!(manager.plugin_ids().len(), 0);
+    /// extension_point!(
+    ///     Exporter: ExporterTrait;
+    ///     fn format(&self) -> &'static str;
+    /// );
+    ///
+    /// struct Csv;
+    /// impl ExporterTrait for Csv {
+    ///     fn format(&self) -> &'static str {
+    ///         "csv"
+    ///     }
+    /// }
+    ///
+    /// struct Parquet;
+    /// impl ExporterTrait for Parquet {
+    ///     fn format(&self) -> &'static str {
+    ///         "parquet"
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct ExportPlugin;
+    ///
+    /// impl Plugin for ExportPlugin {
+    ///     fn id(&self) -> steckrs::PluginID {
+    ///         "export_plugin"
+    ///     }
+    ///     fn description(&self) -> &str {
+    ///         "Exports data"
+    ///     }
+    ///     fn is_enabled(&self) -> bool {
+    ///         true
+    ///     }
+    ///     fn enable(&mut self) {}
+    ///     fn disable(&mut self) {}
+    ///     fn register_hooks(&self, _registry: &mut HookRegistry) -> PluginResult<()> {
+    ///         unreachable!("register_hooks_ctx is implemented")
+    ///     }
+    ///     fn register_hooks_ctx(&self, ctx: &mut PluginContext) -> PluginResult<()> {
+    ///         let id = HookID::for_ep::<Exporter>(self.id(), Some("csv"));
+    ///         ctx.registry_mut().register(&id, Hook::<Exporter>::new(Box::new(Csv), "csv"))?;
+    ///         if ctx.feature_enabled("parquet") {
+    ///             let id = HookID::for_ep::<Exporter>(self.id(), Some("parquet"));
+    ///             let hook = Hook::<Exporter>::new(Box::new(Parquet), "parquet");
+    ///             ctx.registry_mut().register(&id, hook)?;
+    ///         }
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.set_plugin_feature("export_plugin", "parquet", true);
+    /// manager.load_plugin(Box::new(ExportPlugin)).unwrap();
+    ///
+    /// let formats: Vec<_> = manager
+    ///     .get_enabled_hooks_by_ep::<Exporter>()
+    ///     .iter()
+    ///     .map(|(_id, hook)| hook.inner().format())
+    ///     .collect();
+    /// assert_eq!(formats, ["csv", "parquet"]);
     /// ```
-    #[must_use]
-    pub fn new() -> Self {
-        Self {
-            plugins: HashMap::new(),
-            hook_registry: HookRegistry::new(),
-        }
+    fn register_hooks_ctx(&self, ctx: &mut PluginContext) -> PluginResult<()> {
+        self.register_hooks(ctx.registry_mut())
     }
 
-    /// Creates a new plugin manager with an existing hook registry.
+    /// Called when the plugin is loaded, with access to the host through a [`PluginContext`].
     ///
-    /// This allows sharing a hook registry between multiple plugin managers,
-    /// which can be useful for complex applications.
+    /// This is what the [`PluginManager`] actually calls. The default implementation ignores
+    /// the context and calls [`on_load`](Plugin::on_load), so plugins only need to implement one
+    /// of the two.
     ///
-    /// # Examples
+    /// # Errors
     ///
-    /// ```
-    /// use steckrs::{PluginManager, hook::HookRegistry};
+    /// Returns a [`PluginError`] if loading fails.
+    fn on_load_ctx(&mut self, ctx: &mut PluginContext) -> PluginResult<()> {
+        let _ = ctx;
+        self.on_load()
+    }
+
+    /// Called when the plugin is unloaded, with access to the host through a [`PluginContext`].
     ///
-    /// let registry = HookRegistry::new();
-    /// let manager = PluginManager::with_registry(registry);
-    /// ```
-    #[must_use]
-    pub fn with_registry(hook_registry: HookRegistry) -> Self {
-        Self {
-            plugins: HashMap::new(),
-            hook_registry,
-        }
+    /// This is what the [`PluginManager`] actually calls. The default implementation ignores
+    /// the context and calls [`on_unload`](Plugin::on_unload).
+    ///
+    /// The hooks of the plugin are still registered when this is called.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError`] if the unloading cleanup fails.
+    fn on_unload_ctx(&mut self, ctx: &mut PluginContext) -> PluginResult<()> {
+        let _ = ctx;
+        self.on_unload()
     }
 
-    /// Returns a reference to the hook registry.
+    /// Returns the current [`PluginStatus`] of the plugin.
     ///
-    /// The hook registry contains all registered hooks from loaded plugins.
-    #[must_use]
-    pub fn hook_registry(&self) -> &HookRegistry {
-        &self.hook_registry
+    /// The [`PluginManager`] checks this after [`on_load`](Plugin::on_load) succeeded. If the
+    /// plugin reports [`PluginStatus::Failed`], it is unloaded again and
+    /// [`PluginError::LoadedButFailed`] is returned from
+    /// [`load_plugin`](PluginManager::load_plugin).
+    ///
+    /// This lets a plugin that has already partially initialized signal that it is not functional,
+    /// without returning an error from [`on_load`](Plugin::on_load).
+    ///
+    /// The default implementation always returns [`PluginStatus::Ok`].
+    fn status(&self) -> PluginStatus {
+        PluginStatus::Ok
     }
 
-    /// Returns a mutable reference to the hook registry.
+    /// Returns the capabilities this plugin advertises.
     ///
-    /// This can be used to directly manipulate the hook registry if needed.
-    #[must_use]
-    pub fn hook_registry_mut(&mut self) -> &mut HookRegistry {
-        &mut self.hook_registry
+    /// Capabilities are free-form strings that a host can use to negotiate which plugins to route
+    /// work to, see [`PluginManager::plugins_supporting`]. They have no influence on loading or
+    /// enabling the plugin.
+    ///
+    /// The default implementation advertises no capabilities.
+    fn capabilities(&self) -> &[&str] {
+        &[]
     }
 
-    /// Loads a plugin into the plugin manager.
+    /// Returns the tags of this plugin.
     ///
-    /// This will:
-    /// 1. Register the plugin's hooks in the hook registry
-    /// 2. Call the plugin's `on_load` method
-    /// 3. Store the plugin in the manager
+    /// Tags are free-form strings that a host can use to classify plugins, like `"trusted"` or
+    /// `"experimental"`. Unlike [capabilities](Plugin::capabilities), which describe what a plugin
+    /// can do, tags describe how the host should treat it. See
+    /// [`PluginManager::get_enabled_hooks_by_ep_tagged`].
     ///
-    /// # Errors
+    /// The default implementation has no tags.
+    fn tags(&self) -> &[&str] {
+        &[]
+    }
+
+    /// Returns the IDs of the plugins this plugin depends on.
     ///
-    /// Returns a `PluginError` if:
-    /// - A plugin with the same ID is already loaded
-    /// - The plugin's [`on_load`](Plugin::register_hooks) method fails
-    /// - The plugin's [`on_load`](Plugin::on_load) method fails
+    /// Dependencies are used by [`PluginManager::disable_plugin_cascade`] to find the plugins
+    /// that break when another plugin is disabled.
     ///
-    /// If any of the steps fail, this function will try to unload the half-loaded plugin again,
-    /// using [`unload_plugin`](Self::unload_plugin).
+    /// The default implementation has no dependencies.
+    fn dependencies(&self) -> &[PluginID] {
+        &[]
+    }
+
+    /// Returns the version of this plugin.
     ///
-    /// # Panics
+    /// Versions should follow [semantic versioning](https://semver.org), since they are checked
+    /// against the [`dependency_requirements`](Plugin::dependency_requirements) of other plugins.
     ///
-    /// If loading of the plugin and then unloading the half-loaded plugin both fail, this function
-    /// will panic.
+    /// The default implementation returns `"0.0.0"`.
+    #[allow(clippy::unnecessary_literal_bound)] // implementations may return borrowed data
+    fn version(&self) -> &str {
+        "0.0.0"
+    }
+
+    /// Returns version requirements for the [dependencies](Plugin::dependencies) of this plugin.
+    ///
+    /// Each entry is the [`PluginID`] of a dependency and a requirement for its
+    /// [version](Plugin::version), in the syntax of Cargo, like `">=1.2, <2"`.
+    ///
+    /// With the `semver` feature, [`PluginManager::load_plugin`] checks the requirements against
+    /// the dependencies that are already loaded, and fails with
+    /// [`PluginError::VersionConflict`] if one is not satisfied. A requirement or version that
+    /// cannot be parsed is never satisfied. Dependencies that are not loaded yet are not checked,
+    /// so load dependencies first, for example with [`PluginManager::load_plugins`]. Without the
+    /// `semver` feature, the requirements are ignored.
+    ///
+    /// The default implementation has no requirements.
     ///
     /// # Examples
     ///
     /// ```
-    /// use steckrs::{PluginManager, simple_plugin};
+    /// use steckrs::{Plugin, PluginID, PluginManager, error::PluginResult, hook::HookRegistry};
     ///
-    /// simple_plugin!(
-    ///     ExamplePlugin,
-    ///     "example_plugin",
-    ///     "An example plugin",
-    ///     hooks: []
-    /// );
+    /// #[derive(Debug)]
+    /// struct VersionedPlugin {
+    ///     id: PluginID,
+    ///     version: &'static str,
+    ///     requirements: Vec<(PluginID, &'static str)>,
+    /// }
+    ///
+    /// impl Plugin for VersionedPlugin {
+    ///     fn id(&self) -> PluginID {
+    ///         self.id
+    ///     }
+    ///     fn description(&self) -> &str {
+    ///         "A plugin with a version"
+    ///     }
+    ///     fn is_enabled(&self) -> bool {
+    ///         false
+    ///     }
+    ///     fn enable(&mut self) {}
+    ///     fn disable(&mut self) {}
+    ///     fn register_hooks(&self, _registry: &mut HookRegistry) -> PluginResult<()> {
+    ///         Ok(())
+    ///     }
+    ///     fn version(&self) -> &str {
+    ///         self.version
+    ///     }
+    ///     fn dependency_requirements(&self) -> &[(PluginID, &str)] {
+    ///         &self.requirements
+    ///     }
+    /// }
     ///
     /// let mut manager = PluginManager::new();
-    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
-    /// assert!(manager.get_plugin("example_plugin").is_some());
+    /// let config = VersionedPlugin { id: "config", version: "1.1.0", requirements: vec![] };
+    /// manager.load_plugin(Box::new(config)).unwrap();
+    ///
+    /// let network = VersionedPlugin {
+    ///     id: "network",
+    ///     version: "0.3.0",
+    ///     requirements: vec![("config", ">=1.2")],
+    /// };
+    /// let result = manager.load_plugin(Box::new(network));
+    ///
+    /// #[cfg(feature = "semver")]
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(steckrs::error::PluginError::VersionConflict { ref found, .. }) if found == "1.1.0"
+    /// ));
+    /// #[cfg(not(feature = "semver"))]
+    /// assert!(result.is_ok());
     /// ```
-    pub fn load_plugin(&mut self, mut plugin: Box<dyn Plugin>) -> PluginResult<()> {
-        let id = plugin.id();
-        if self.plugins.contains_key(id) {
-            return Err(error::PluginError::AlreadyLoaded(id.into()));
-        }
+    fn dependency_requirements(&self) -> &[(PluginID, &str)] {
+        &[]
+    }
 
-        // register the hooks
-        if let Err(e) = plugin.register_hooks(self.hook_registry_mut()) {
-            self.handle_error_during_load(&e, id);
-            return Err(e);
-        }
-        // Load the plugin
-        if let Err(e) = plugin.on_load() {
-            self.handle_error_during_load(&e, id);
-            return Err(e);
-        }
-
-        // Store the plugin
-        self.plugins.insert(id, plugin);
-
-        Ok(())
-    }
-
-    /// Internal helper to handle errors during plugin loading.
+    /// Returns the load phase of this plugin.
     ///
-    /// If a plugin fails during loading, this will attempt to clean up
-    /// by unloading the plugin.
-    fn handle_error_during_load(&mut self, e: &PluginError, plugin_id: PluginID) {
-        error!("Could not register hooks of plugin {plugin_id}: {e}");
-        warn!("Trying to unload the plugin again... Will crash if this fails");
-        self.unload_plugin(plugin_id)
-            .expect("Could not unload bad plugin again");
+    /// Phases are a coarse way to order plugins without declaring
+    /// [dependencies](Plugin::dependencies): [`PluginManager::load_plugins`] loads plugins with a
+    /// lower phase first. For example, core plugins could use phase `0`, feature plugins the
+    /// default and user interface plugins `200`.
+    ///
+    /// The default implementation returns `100`.
+    fn load_phase(&self) -> u8 {
+        100
     }
 
-    /// Unloads a plugin by ID.
+    /// Returns the priority of this plugin.
     ///
-    /// This will:
-    /// 1. Call the plugin's `on_unload` method for cleanup
-    /// 2. Remove all hooks registered by the plugin
-    /// 3. Remove the plugin from the manager
+    /// Hooks of plugins with a higher priority come first in
+    /// [`PluginManager::get_enabled_hooks_by_ep_prioritized`]. The host can override the priority
+    /// at runtime with [`PluginManager::set_plugin_priority`].
     ///
-    /// # Errors
+    /// The default implementation returns `0`.
+    fn priority(&self) -> i32 {
+        0
+    }
+}
+
+impl dyn Plugin {
+    /// Returns the plugin as [`&dyn Any`](Any), to downcast it to its concrete type.
     ///
-    /// Returns a [`PluginError`] if:
-    /// - The plugin's [`on_unload`](Plugin::on_unload) method fails
+    /// This is provided for every plugin, so plugins do not need to implement it themselves.
     ///
     /// # Examples
     ///
@@ -588,26 +851,21 @@ impl PluginManager {
     ///
     /// let mut manager = PluginManager::new();
     /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
-    /// manager.unload_plugin("example_plugin").unwrap();
-    /// assert!(manager.get_plugin("example_plugin").is_none());
+    ///
+    /// let plugin = manager.get_plugin(ExamplePlugin::ID).unwrap();
+    /// assert!(plugin.as_any().downcast_ref::<ExamplePlugin>().is_some());
     /// ```
-    pub fn unload_plugin(&mut self, id: PluginID) -> PluginResult<()> {
-        if let Some(mut plugin) = self.plugins.remove(id) {
-            // Call on_unload for cleanup
-            plugin.on_unload()?;
-
-            // Remove all hooks registered by this plugin
-            self.hook_registry.deregister_hooks_for_plugin(id);
-        }
-        Ok(())
+    #[must_use]
+    pub fn as_any(&self) -> &dyn Any {
+        self
     }
 
-    /// Gets a reference to a plugin by ID.
+    /// Returns the plugin as [`&mut dyn Any`](Any), to downcast it to its concrete type.
     ///
     /// # Examples
     ///
     /// ```
-    /// use steckrs::{PluginManager, simple_plugin};
+    /// use steckrs::{Plugin, PluginManager, simple_plugin};
     ///
     /// simple_plugin!(
     ///     ExamplePlugin,
@@ -619,78 +877,295 @@ impl PluginManager {
     /// let mut manager = PluginManager::new();
     /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
     ///
-    /// let plugin = manager.get_plugin("example_plugin");
-    /// assert!(plugin.is_some());
-    /// assert_eq!(plugin.unwrap().id(), "example_plugin");
+    /// let plugin = manager.get_plugin_mut(ExamplePlugin::ID).unwrap();
+    /// let example: &mut ExamplePlugin = plugin.as_any_mut().downcast_mut().unwrap();
+    /// example.enable();
+    ///
+    /// assert_eq!(manager.plugin_is_enabled(ExamplePlugin::ID), Some(true));
     /// ```
     #[must_use]
-    pub fn get_plugin(&self, id: PluginID) -> Option<&dyn Plugin> {
-        self.plugins.get(id).map(std::convert::AsRef::as_ref)
+    pub fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
     }
+}
 
-    /// Gets a mutable reference to a plugin by ID.
+/// A source of plugin reloads, polled by [`PluginManager::apply_reloads`].
+///
+/// steckrs only provides the mechanism for hot reloading, not the trigger. Implement this trait on
+/// top of whatever tells your application that a plugin changed, like a file watcher or a message
+/// from the network.
+///
+/// # Examples
+///
+/// ```
+/// use steckrs::{Plugin, PluginID, ReloadSource};
+///
+/// #[derive(Default)]
+/// struct QueuedReloads {
+///     queue: Vec<(PluginID, Box<dyn Plugin>)>,
+/// }
+///
+/// impl ReloadSource for QueuedReloads {
+///     fn poll(&mut self) -> Vec<(PluginID, Box<dyn Plugin>)> {
+///         std::mem::take(&mut self.queue)
+///     }
+/// }
+/// ```
+pub trait ReloadSource {
+    /// Returns the plugins that should be reloaded since the last call.
     ///
-    /// This can be used to modify a plugin's state after it's been loaded.
+    /// Each entry pairs the [`PluginID`] of a loaded plugin with the new version of that plugin.
+    /// Return an empty [`Vec`] if nothing changed.
+    fn poll(&mut self) -> Vec<(PluginID, Box<dyn Plugin>)>;
+}
+
+/// Constructor of a plugin, see [`PluginFactory`].
+type PluginConstructor = Box<dyn Fn() -> Box<dyn Plugin> + Send + Sync>;
+
+/// Creates plugins by [`PluginID`].
+///
+/// A factory maps plugin IDs to constructors, so that the plugins to load can be chosen at
+/// runtime, for example from a [`PluginManifest`] with
+/// [`PluginManager::load_from_manifest`].
+///
+/// # Examples
+///
+/// ```
+/// use steckrs::{PluginFactory, simple_plugin};
+///
+/// simple_plugin!(
+///     ExamplePlugin,
+///     "example_plugin",
+///     "An example plugin",
+///     hooks: []
+/// );
+///
+/// let mut factory = PluginFactory::new();
+/// factory.register(ExamplePlugin::ID, || Box::new(ExamplePlugin::new()));
+///
+/// assert!(factory.contains(ExamplePlugin::ID));
+/// assert_eq!(factory.create(ExamplePlugin::ID).unwrap().id(), ExamplePlugin::ID);
+/// assert!(factory.create("unknown_plugin").is_none());
+/// ```
+#[derive(Default)]
+pub struct PluginFactory {
+    constructors: HashMap<PluginID, PluginConstructor>,
+}
+
+impl PluginFactory {
+    /// Creates a new empty factory.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the constructor for the plugin with the given ID.
+    ///
+    /// The constructor has to create plugins whose [`id`](Plugin::id) is `id`. Registering a
+    /// constructor for an ID that already has one replaces the old constructor.
+    pub fn register(
+        &mut self,
+        id: PluginID,
+        constructor: impl Fn() -> Box<dyn Plugin> + Send + Sync + 'static,
+    ) {
+        self.constructors.insert(id, Box::new(constructor));
+    }
+
+    /// Creates a new instance of the plugin with the given ID, or returns [`None`] if no
+    /// constructor is registered for it.
+    #[must_use]
+    pub fn create(&self, id: PluginID) -> Option<Box<dyn Plugin>> {
+        self.constructors.get(id).map(|constructor| constructor())
+    }
+
+    /// Returns `true` if a constructor is registered for the given ID.
+    #[must_use]
+    pub fn contains(&self, id: PluginID) -> bool {
+        self.constructors.contains_key(id)
+    }
+}
+
+impl Debug for PluginFactory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PluginFactory")
+            .field("constructors", &self.constructors.keys())
+            .finish()
+    }
+}
+
+/// A list of plugins to load, and whether they should be enabled.
+///
+/// Manifests are meant to come from configuration. With the `serde` feature, they can be
+/// serialized and deserialized. Load the plugins of a manifest with
+/// [`PluginManager::load_from_manifest`].
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PluginManifest {
+    /// The plugins to load, in order
+    pub plugins: Vec<ManifestEntry>,
+}
+
+/// A single plugin of a [`PluginManifest`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ManifestEntry {
+    /// ID of the plugin to load
+    pub id: PluginIDOwned,
+    /// Whether the plugin should be enabled after loading
+    pub enabled: bool,
+}
+
+/// Which plugins of a [`PluginManager`] are loaded, and whether they are enabled.
+///
+/// Created with [`PluginManager::state`], and applied again with
+/// [`PluginManager::apply_enabled_state`]. With the `serde` feature, the state can be serialized,
+/// for example to restore the enabled plugins on the next run of the program.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PluginManagerState {
+    /// The loaded plugins and whether they are enabled, sorted by [`PluginID`]
+    pub plugins: Vec<(PluginIDOwned, bool)>,
+}
+
+/// Manages plugin loading, execution, and lifecycle.
+///
+/// The [`PluginManager`] is the core component of the steckrs plugin system,
+/// responsible for:
+/// - Loading and unloading plugins
+/// - Enabling and disabling plugins
+/// - Maintaining the hook registry
+/// - Tracking loaded plugins
+///
+/// # Examples
+///
+/// ```
+/// use steckrs::{PluginManager, simple_plugin, extension_point};
+///
+/// // Define extension point
+/// extension_point!(
+///     ExampleExt: ExampleTrait;
+///     fn do_something(&self) -> &'static str;
+/// );
+///
+/// // Define plugin
+/// simple_plugin!(
+///     ExamplePlugin,
+///     "example_plugin",
+///     "An example plugin",
+///     hooks: [(ExampleExt, ExampleHook)]
+/// );
+///
+/// // Hook implementation
+/// struct ExampleHook;
+/// impl ExampleTrait for ExampleHook {
+///     fn do_something(&self) -> &'static str {
+///         "I did something!"
+///     }
+/// }
+///
+/// // Plugin management
+/// let mut manager = PluginManager::new();
+/// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+/// manager.enable_plugin(ExamplePlugin::ID).unwrap();
+///
+/// // Use plugin hooks
+/// // Get all enabled hooks (plugins could be disabled)
+/// let hooks = manager.get_enabled_hooks_by_ep::<ExampleExt>();
+/// for (_id, hook) in hooks {
+///     assert_eq!(hook.inner().do_something(), "I did something!");
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct PluginManager {
+    plugins: HashMap<PluginID, Box<dyn Plugin>>,
+    hook_registry: HookRegistry,
+    generation: u64,
+    failed_loads: Vec<(PluginIDOwned, PluginError)>,
+    groups: HashMap<String, Vec<PluginID>>,
+    invocation_counts: Mutex<HashMap<hook::HookID, u64>>,
+    priority_overrides: HashMap<PluginID, i32>,
+    plugin_features: HashMap<PluginID, HashSet<String>>,
+    locked: bool,
+    #[cfg(feature = "semver")]
+    version_requirements: HashMap<PluginID, semver::VersionReq>,
+}
+
+impl PluginManager {
+    /// Maximum amount of records kept by [`failed_loads`](Self::failed_loads).
+    pub const MAX_FAILED_LOADS: usize = 64;
+
+    /// Creates a new empty plugin manager.
     ///
     /// # Examples
     ///
     /// ```
-    /// use steckrs::{PluginManager, simple_plugin};
-    ///
-    /// simple_plugin!(
-    ///     ExamplePlugin,
-    ///     "example_plugin",
-    ///     "An example plugin",
-    ///     hooks: []
-    /// );
-    ///
-    /// let mut manager = PluginManager::new();
-    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    /// use steckrs::PluginManager;
     ///
-    /// let plugin = manager.get_plugin_mut("example_plugin");
-    /// assert!(plugin.is_some());
+    /// let manager = PluginManager::new();
+    /// assert_eq!(manager.plugin_ids().len(), 0);
     /// ```
     #[must_use]
-    pub fn get_plugin_mut(&mut self, id: PluginID) -> Option<&mut dyn Plugin> {
-        self.plugins.get_mut(id).map(std::convert::AsMut::as_mut)
+    pub fn new() -> Self {
+        Self {
+            plugins: HashMap::new(),
+            hook_registry: HookRegistry::new(),
+            generation: 0,
+            failed_loads: Vec::new(),
+            groups: HashMap::new(),
+            invocation_counts: Mutex::new(HashMap::new()),
+            priority_overrides: HashMap::new(),
+            plugin_features: HashMap::new(),
+            locked: false,
+            #[cfg(feature = "semver")]
+            version_requirements: HashMap::new(),
+        }
     }
 
-    /// Gets all plugin IDs.
+    /// Creates a new plugin manager with an existing hook registry.
+    ///
+    /// This allows sharing a hook registry between multiple plugin managers,
+    /// which can be useful for complex applications.
     ///
     /// # Examples
     ///
     /// ```
-    /// use steckrs::{PluginManager, simple_plugin};
-    ///
-    /// simple_plugin!(
-    ///     Plugin1,
-    ///     "plugin1",
-    ///     "First plugin",
-    ///     hooks: []
-    /// );
-    ///
-    /// simple_plugin!(
-    ///     Plugin2,
-    ///     "plugin2",
-    ///     "Second plugin",
-    ///     hooks: []
-    /// );
-    ///
-    /// let mut manager = PluginManager::new();
-    /// manager.load_plugin(Box::new(Plugin1::new())).unwrap();
-    /// manager.load_plugin(Box::new(Plugin2::new())).unwrap();
+    /// use steckrs::{PluginManager, hook::HookRegistry};
     ///
-    /// let ids = manager.plugin_ids();
-    /// assert_eq!(ids.len(), 2);
-    /// assert!(ids.contains(&"plugin1"));
-    /// assert!(ids.contains(&"plugin2"));
+    /// let registry = HookRegistry::new();
+    /// let manager = PluginManager::with_registry(registry);
     /// ```
     #[must_use]
-    pub fn plugin_ids(&self) -> Vec<PluginID> {
-        self.plugins.keys().copied().collect()
+    pub fn with_registry(hook_registry: HookRegistry) -> Self {
+        Self {
+            plugins: HashMap::new(),
+            hook_registry,
+            generation: 0,
+            failed_loads: Vec::new(),
+            groups: HashMap::new(),
+            invocation_counts: Mutex::new(HashMap::new()),
+            priority_overrides: HashMap::new(),
+            plugin_features: HashMap::new(),
+            locked: false,
+            #[cfg(feature = "semver")]
+            version_requirements: HashMap::new(),
+        }
     }
 
-    /// Gets all plugins.
+    /// Returns the current generation of the plugin manager.
+    ///
+    /// The generation is a counter that changes on every mutating operation of the plugin manager:
+    /// - loading or unloading a plugin
+    /// - enabling or disabling a plugin
+    /// - accessing the hook registry mutably through [`hook_registry_mut`](Self::hook_registry_mut)
+    ///
+    /// Getting mutable hooks (e.g. with
+    /// [`get_enabled_hooks_by_ep_mut`](Self::get_enabled_hooks_by_ep_mut)) does not change the
+    /// generation, as that only changes the state of the hooks, not which hooks exist.
+    ///
+    /// Callers can cache state derived from the plugin manager, like sorted hook lists or the
+    /// result of [`enabled_hook_ids`](Self::enabled_hook_ids), keyed by the generation, and only
+    /// recompute it when the generation changed. Two equal generations of the same manager mean
+    /// that nothing was changed in between.
     ///
     /// # Examples
     ///
@@ -698,107 +1173,133 @@ impl PluginManager {
     /// use steckrs::{PluginManager, simple_plugin};
     ///
     /// simple_plugin!(
-    ///     Plugin1,
-    ///     "plugin1",
-    ///     "First plugin",
+    ///     ExamplePlugin,
+    ///     "example_plugin",
+    ///     "An example plugin",
     ///     hooks: []
     /// );
     ///
     /// let mut manager = PluginManager::new();
-    /// manager.load_plugin(Box::new(Plugin1::new())).unwrap();
+    /// let before = manager.generation();
     ///
-    /// let plugins = manager.plugins();
-    /// assert_eq!(plugins.len(), 1);
-    /// assert_eq!(plugins[0].id(), "plugin1");
+    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    /// assert_ne!(manager.generation(), before);
+    ///
+    /// let before = manager.generation();
+    /// manager.enable_plugin(ExamplePlugin::ID).unwrap();
+    /// assert_ne!(manager.generation(), before);
+    ///
+    /// // reading does not change the generation
+    /// let before = manager.generation();
+    /// let _ = manager.plugins();
+    /// let _ = manager.hook_registry();
+    /// assert_eq!(manager.generation(), before);
+    ///
+    /// // mutable access to the registry does
+    /// let _ = manager.hook_registry_mut();
+    /// assert_ne!(manager.generation(), before);
     /// ```
+    #[inline]
     #[must_use]
-    pub fn plugins(&self) -> Vec<&dyn Plugin> {
-        self.plugins
-            .values()
-            .map(std::convert::AsRef::as_ref)
-            .collect()
+    pub fn generation(&self) -> u64 {
+        self.generation
     }
 
-    /// Gets all enabled plugins.
+    /// Internal helper to mark that something about the managed plugins changed.
+    #[inline]
+    fn bump_generation(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Returns a reference to the hook registry.
     ///
-    /// # Examples
-    ///
-    /// ```
-    /// use steckrs::{PluginManager, simple_plugin};
-    ///
-    /// simple_plugin!(
-    ///     Plugin1,
-    ///     "plugin1",
-    ///     "First plugin",
-    ///     hooks: []
-    /// );
-    ///
-    /// simple_plugin!(
-    ///     Plugin2,
-    ///     "plugin2",
-    ///     "Second plugin",
-    ///     hooks: []
-    /// );
+    /// The hook registry contains all registered hooks from loaded plugins.
+    #[must_use]
+    pub fn hook_registry(&self) -> &HookRegistry {
+        &self.hook_registry
+    }
+
+    /// Returns a mutable reference to the hook registry.
     ///
-    /// let mut manager = PluginManager::new();
-    /// manager.load_plugin(Box::new(Plugin1::new())).unwrap();
-    /// manager.load_plugin(Box::new(Plugin2::new())).unwrap();
-    /// manager.enable_plugin("plugin1").unwrap();
+    /// This can be used to directly manipulate the hook registry if needed.
     ///
-    /// let enabled = manager.enabled_plugins();
-    /// assert_eq!(enabled.len(), 1);
-    /// assert_eq!(enabled[0].id(), "plugin1");
-    /// ```
+    /// Since the registry might be changed through the returned reference, this advances the
+    /// [`generation`](Self::generation) of the plugin manager.
     #[must_use]
-    pub fn enabled_plugins(&self) -> Vec<&dyn Plugin> {
-        self.plugins
-            .values()
-            .filter(|p| p.is_enabled())
-            .map(std::convert::AsRef::as_ref)
-            .collect()
+    pub fn hook_registry_mut(&mut self) -> &mut HookRegistry {
+        self.bump_generation();
+        &mut self.hook_registry
     }
 
-    /// Quickly check if a [`Plugin`] with a specific [`PluginID`] is enabled.
+    /// Returns the number of hooks in the hook registry, of enabled and disabled plugins alike.
     ///
-    /// This will return [`None`] if the [`Plugin`] with that [`PluginID`] was not found, otherwise
-    /// `Some(enabled)`, where `enabled` is gotten with [`Plugin::is_enabled`].
+    /// See [`HookRegistry::len`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use steckrs::{PluginManager, simple_plugin};
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
     ///
-    /// simple_plugin!(
-    ///     Plugin,
-    ///     "plugin",
-    ///     "Some plugin",
-    ///     hooks: []
+    /// extension_point!(
+    ///     Greeter: GreeterTrait;
+    ///     fn greet(&self) -> String;
     /// );
     ///
-    /// let mut manager = PluginManager::new();
-    /// manager.load_plugin(Box::new(Plugin::new())).unwrap();
-    /// manager.enable_plugin("plugin").unwrap();
+    /// struct Hello;
+    /// impl GreeterTrait for Hello {
+    ///     fn greet(&self) -> String {
+    ///         "hello".to_string()
+    ///     }
+    /// }
     ///
-    /// assert_eq!(manager.plugin_is_enabled("plugin"), Some(true));
-    /// assert_eq!(manager.plugin_is_enabled("nope"), None);
+    /// simple_plugin!(
+    ///     HelloPlugin,
+    ///     "hello_plugin",
+    ///     "Says hello",
+    ///     hooks: [(Greeter, Hello)]
+    /// );
     ///
-    /// manager.disable_plugin("plugin").unwrap();
+    /// let mut manager = PluginManager::new();
+    /// assert_eq!(manager.hooks_len(), 0);
     ///
-    /// assert_eq!(manager.plugin_is_enabled("plugin"), Some(false));
+    /// manager.load_plugin(Box::new(HelloPlugin::new())).unwrap();
+    /// assert_eq!(manager.hooks_len(), 1);
     /// ```
-    #[inline]
     #[must_use]
-    pub fn plugin_is_enabled(&self, id: PluginID) -> Option<bool> {
-        Some(self.plugins.get(id)?.is_enabled())
+    pub fn hooks_len(&self) -> usize {
+        self.hook_registry.len()
     }
 
-    /// Enables a plugin by ID.
+    /// Loads a plugin into the plugin manager.
     ///
-    /// Note that plugins are disabled by default
+    /// This will:
+    /// 1. Register the plugin's hooks in the hook registry
+    /// 2. Call the plugin's `on_load` method
+    /// 3. Check the plugin's [`status`](Plugin::status)
+    /// 4. Store the plugin in the manager
     ///
     /// # Errors
     ///
-    /// Returns a [`PluginError::NotFound`] if no plugin with the given ID is loaded.
+    /// Returns a `PluginError` if:
+    /// - The manager is [locked](Self::lock) ([`PluginError::Locked`])
+    /// - A plugin with the same ID is already loaded
+    /// - With the `semver` feature, the version of the plugin does not satisfy a
+    ///   requirement of the host set with `require_version` ([`PluginError::IncompatibleVersion`])
+    ///   or the plugin's [`dependency_requirements`](Plugin::dependency_requirements)
+    ///   ([`PluginError::VersionConflict`])
+    /// - The plugin's [`register_hooks`](Plugin::register_hooks) method fails
+    /// - The plugin's [`on_load`](Plugin::on_load) method fails
+    /// - The plugin reports [`PluginStatus::Failed`] after loading, in which case
+    ///   [`PluginError::LoadedButFailed`] is returned
+    ///
+    /// If any of the steps fail, this function will try to unload the half-loaded plugin again,
+    /// using [`unload_plugin`](Self::unload_plugin). The error is also recorded, see
+    /// [`failed_loads`](Self::failed_loads).
+    ///
+    /// # Panics
+    ///
+    /// If loading of the plugin and then unloading the half-loaded plugin both fail, this function
+    /// will panic.
     ///
     /// # Examples
     ///
@@ -814,192 +1315,3849 @@ impl PluginManager {
     ///
     /// let mut manager = PluginManager::new();
     /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
-    /// manager.enable_plugin("example_plugin").unwrap();
-    ///
-    /// let plugin = manager.get_plugin("example_plugin").unwrap();
-    /// assert!(plugin.is_enabled());
+    /// assert!(manager.get_plugin("example_plugin").is_some());
     /// ```
-    pub fn enable_plugin(&mut self, id: PluginID) -> PluginResult<()> {
-        match self.plugins.get_mut(id) {
-            Some(plugin) => {
-                plugin.enable();
-                Ok(())
-            }
-            None => Err(error::PluginError::NotFound(id.into())),
+    pub fn load_plugin(&mut self, mut plugin: Box<dyn Plugin>) -> PluginResult<()> {
+        self.check_unlocked()?;
+        let id = plugin.id();
+        let _span = info_span!("load_plugin", plugin_id = id).entered();
+        if self.plugins.contains_key(id) {
+            return Err(error::PluginError::AlreadyLoaded(id.into()));
+        }
+        if let Err(e) = self.check_version_requirements(plugin.as_ref()) {
+            self.record_failed_load(id, e.clone());
+            return Err(e);
+        }
+
+        if let Err(e) = self.initialize_plugin(id, plugin.as_mut()) {
+            self.handle_error_during_load(id, &e, plugin);
+            self.record_failed_load(id, e.clone());
+            return Err(e);
         }
+
+        self.store_loaded_plugin(id, plugin);
+        Ok(())
     }
 
-    /// Disables a plugin by ID.
+    /// Loads a plugin like [`load_plugin`](Self::load_plugin), but turns panics of the plugin into
+    /// errors.
+    ///
+    /// If the plugin panics in [`register_hooks`](Plugin::register_hooks) or
+    /// [`on_load`](Plugin::on_load), the panic is caught with [`std::panic::catch_unwind`], all
+    /// hooks the plugin registered so far are removed, and the plugin is dropped without calling
+    /// [`on_unload`](Plugin::on_unload). This protects the host against bugs in third party
+    /// plugins, like a stray `unwrap`.
+    ///
+    /// This is best-effort:
+    /// - Panics are only caught if the program is built with `panic = "unwind"`, which is the
+    ///   default. With `panic = "abort"`, the program still aborts.
+    /// - The panic hook runs before the panic is caught, so the panic message is still printed.
+    /// - The plugin and the [`HookRegistry`] are not required to be
+    ///   [`UnwindSafe`](std::panic::UnwindSafe). Hooks that share state with the rest of the
+    ///   program (e.g. through an [`Arc`](std::sync::Arc)) may leave that state inconsistent.
     ///
     /// # Errors
     ///
-    /// Returns a [`PluginError::NotFound`] if no plugin with the given ID is loaded.
+    /// Returns a [`PluginError::Panicked`] if the plugin panicked while loading, and the same
+    /// errors as [`load_plugin`](Self::load_plugin) otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`load_plugin`](Self::load_plugin), which does not
+    /// include panics of the plugin itself.
     ///
     /// # Examples
     ///
     /// ```
-    /// use steckrs::{PluginManager, simple_plugin};
+    /// use steckrs::{Plugin, PluginManager, error::{PluginError, PluginResult}, hook::HookRegistry};
     ///
-    /// simple_plugin!(
-    ///     ExamplePlugin,
-    ///     "example_plugin",
-    ///     "An example plugin",
-    ///     hooks: []
-    /// );
+    /// #[derive(Debug)]
+    /// struct BuggyPlugin;
+    ///
+    /// impl Plugin for BuggyPlugin {
+    ///     fn id(&self) -> steckrs::PluginID {
+    ///         "buggy_plugin"
+    ///     }
+    ///     fn description(&self) -> &str {
+    ///         "Does not check its configuration"
+    ///     }
+    ///     fn is_enabled(&self) -> bool {
+    ///         false
+    ///     }
+    ///     fn enable(&mut self) {}
+    ///     fn disable(&mut self) {}
+    ///     fn register_hooks(&self, _registry: &mut HookRegistry) -> PluginResult<()> {
+    ///         Ok(())
+    ///     }
+    ///     fn on_load(&mut self) -> PluginResult<()> {
+    ///         let config: Option<&str> = None;
+    ///         config.expect("no configuration");
+    ///         Ok(())
+    ///     }
+    /// }
     ///
     /// let mut manager = PluginManager::new();
-    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
-    /// manager.enable_plugin("example_plugin").unwrap();
-    /// manager.disable_plugin("example_plugin").unwrap();
+    /// let err = manager.load_plugin_catch(Box::new(BuggyPlugin)).unwrap_err();
     ///
-    /// let plugin = manager.get_plugin("example_plugin").unwrap();
-    /// assert!(!plugin.is_enabled());
+    /// assert!(matches!(
+    ///     err,
+    ///     PluginError::Panicked { ref message, .. } if message == "no configuration"
+    /// ));
+    /// assert!(manager.get_plugin("buggy_plugin").is_none());
     /// ```
-    pub fn disable_plugin(&mut self, id: PluginID) -> PluginResult<()> {
-        match self.plugins.get_mut(id) {
-            Some(plugin) => {
-                plugin.disable();
+    pub fn load_plugin_catch(&mut self, mut plugin: Box<dyn Plugin>) -> PluginResult<()> {
+        self.check_unlocked()?;
+        let id = plugin.id();
+        let _span = info_span!("load_plugin", plugin_id = id).entered();
+        if self.plugins.contains_key(id) {
+            return Err(error::PluginError::AlreadyLoaded(id.into()));
+        }
+        if let Err(e) = self.check_version_requirements(plugin.as_ref()) {
+            self.record_failed_load(id, e.clone());
+            return Err(e);
+        }
+
+        let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            self.initialize_plugin(id, plugin.as_mut())
+        }));
+        match outcome {
+            Ok(Ok(())) => {
+                self.store_loaded_plugin(id, plugin);
                 Ok(())
             }
-            None => Err(error::PluginError::NotFound(id.into())),
+            Ok(Err(e)) => {
+                self.handle_error_during_load(id, &e, plugin);
+                self.record_failed_load(id, e.clone());
+                Err(e)
+            }
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|s| (*s).to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "unknown panic payload".to_string());
+                error!("Plugin {id} panicked while loading: {message}");
+                // the plugin may have registered some hooks before panicking
+                self.hook_registry.deregister_hooks_for_plugin(id);
+                self.bump_generation();
+                let e = PluginError::Panicked {
+                    plugin: id.into(),
+                    message,
+                };
+                self.record_failed_load(id, e.clone());
+                Err(e)
+            }
         }
     }
 
-    /// Gets all hooks of enabled [Plugins](Plugin) for a specific [`ExtensionPoint`] type.
+    /// Loads a plugin as an instance with its own ID.
     ///
-    /// This method filters hooks by both extension point type and plugin enabled status,
-    /// returning only hooks from enabled plugins.
+    /// Plugin IDs must be unique, so a plugin type can usually only be loaded once. With this, the
+    /// same plugin type can be loaded multiple times, e.g. with different configurations. The
+    /// plugin is stored under `instance_id` instead of its own [`id`](Plugin::id), which then
+    /// only labels the type of the plugin. Use `instance_id` for everything else, like
+    /// [`enable_plugin`](Self::enable_plugin) or [`unload_plugin`](Self::unload_plugin).
     ///
-    /// # Type Parameters
+    /// The hooks from [`register_hooks`](Plugin::register_hooks) are registered with
+    /// `instance_id` as their [`PluginID`], whatever the plugin used. Hooks registered in
+    /// [`on_load_ctx`](Plugin::on_load_ctx) should use [`PluginContext::plugin_id`], which is
+    /// `instance_id` too.
     ///
-    /// - `E`: The [`ExtensionPoint`] type
+    /// Otherwise, this works like [`load_plugin`](Self::load_plugin).
+    /// [`swap_plugin`](Self::swap_plugin) does not support instances, since it finds the plugin
+    /// to replace by the ID of the new plugin.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A vector of tuples containing references to [`HookID`](crate::hook::HookID)s and hooks registered for the [`ExtensionPoint`]
-    /// from enabled plugins.
+    /// Returns the same errors as [`load_plugin`](Self::load_plugin), with `instance_id` as the
+    /// plugin.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`load_plugin`](Self::load_plugin).
     ///
     /// # Examples
     ///
     /// ```
-    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    /// use steckrs::{extension_point, register_hook, Plugin, PluginManager, error::PluginResult, hook::HookRegistry};
     ///
     /// extension_point!(
-    ///     Logger: LoggerTrait;
-    ///     fn log(&self, message: &str);
+    ///     Greeter: GreeterTrait;
+    ///     fn greet(&self) -> String;
     /// );
     ///
-    /// struct ConsoleLogger;
-    /// impl LoggerTrait for ConsoleLogger {
-    ///     fn log(&self, message: &str) {
-    ///         // In a real implementation, this would print to console
+    /// struct FixedGreeter(String);
+    /// impl GreeterTrait for FixedGreeter {
+    ///     fn greet(&self) -> String {
+    ///         self.0.clone()
     ///     }
     /// }
     ///
-    /// simple_plugin!(
-    ///     LoggerPlugin,
-    ///     "logger_plugin",
-    ///     "Basic logging plugin",
-    ///     hooks: [(Logger, ConsoleLogger)]
-    /// );
+    /// #[derive(Debug)]
+    /// struct GreeterPlugin {
+    ///     greeting: &'static str,
+    /// }
+    ///
+    /// impl Plugin for GreeterPlugin {
+    ///     fn id(&self) -> steckrs::PluginID {
+    ///         "greeter_plugin"
+    ///     }
+    ///     fn description(&self) -> &str {
+    ///         "Greets with a configurable greeting"
+    ///     }
+    ///     fn is_enabled(&self) -> bool {
+    ///         true
+    ///     }
+    ///     fn enable(&mut self) {}
+    ///     fn disable(&mut self) {}
+    ///     fn register_hooks(&self, registry: &mut HookRegistry) -> PluginResult<()> {
+    ///         register_hook!(registry, self.id(), Greeter, FixedGreeter(self.greeting.to_string()));
+    ///         Ok(())
+    ///     }
+    /// }
     ///
     /// let mut manager = PluginManager::new();
-    /// manager.load_plugin(Box::new(LoggerPlugin::new())).unwrap();
-    /// manager.enable_plugin(LoggerPlugin::ID).unwrap();
+    /// manager.load_instance("english", Box::new(GreeterPlugin { greeting: "Hello" })).unwrap();
+    /// manager.load_instance("german", Box::new(GreeterPlugin { greeting: "Hallo" })).unwrap();
     ///
-    /// // Get all enabled hooks for the Logger extension point
-    /// let hooks = manager.get_enabled_hooks_by_ep::<Logger>();
-    /// assert_eq!(hooks.len(), 1);
+    /// let hooks = manager.get_enabled_hooks_by_ep::<Greeter>();
+    /// assert_eq!(hooks.len(), 2);
+    /// assert_eq!(hooks[0].0.plugin_id, "english");
+    /// assert_eq!(hooks[1].1.inner().greet(), "Hallo");
     ///
-    /// // Use the hook
-    /// for (id, hook) in hooks {
-    ///     assert_eq!(id.plugin_id, "logger_plugin");
-    ///     hook.inner().log("Hello from logger!");
-    /// }
+    /// manager.unload_plugin("german").unwrap();
+    /// assert_eq!(manager.get_enabled_hooks_by_ep::<Greeter>().len(), 1);
     /// ```
-    #[must_use]
-    pub fn get_enabled_hooks_by_ep<E: ExtensionPoint>(
-        &self,
-    ) -> Vec<(&hook::HookID, &hook::Hook<E>)> {
-        self.hook_registry()
-            .get_by_extension_point()
-            .into_iter()
-            .filter(|(id, _hook)| {
-                if let Some(plugin) = self.plugins.get(id.plugin_id) {
-                    plugin.is_enabled()
-                } else {
-                    false
-                }
-            })
-            .collect()
+    pub fn load_instance(
+        &mut self,
+        instance_id: PluginID,
+        mut plugin: Box<dyn Plugin>,
+    ) -> PluginResult<()> {
+        self.check_unlocked()?;
+        let _span = info_span!("load_plugin", plugin_id = instance_id).entered();
+        if self.plugins.contains_key(instance_id) {
+            return Err(error::PluginError::AlreadyLoaded(instance_id.into()));
+        }
+        if let Err(e) = self.check_version_requirements(plugin.as_ref()) {
+            self.record_failed_load(instance_id, e.clone());
+            return Err(e);
+        }
+
+        if let Err(e) = self.initialize_plugin(instance_id, plugin.as_mut()) {
+            self.handle_error_during_load(instance_id, &e, plugin);
+            self.record_failed_load(instance_id, e.clone());
+            return Err(e);
+        }
+
+        self.store_loaded_plugin(instance_id, plugin);
+        Ok(())
     }
 
-    /// Gets all mutable hooks of enabled [Plugins](Plugin) for a specific [`ExtensionPoint`] type.
+    /// Loads a plugin like [`load_plugin`](Self::load_plugin), and returns a [`PluginGuard`] that
+    /// unloads it again when dropped.
     ///
-    /// This method filters hooks by both extension point type and plugin enabled status,
-    /// returning only hooks from enabled plugins.
+    /// This is useful for plugins that are only needed for a short time, like in tests or for a
+    /// temporary feature, since the plugin cannot be forgotten in the manager.
     ///
-    /// # Type Parameters
+    /// The guard holds the mutable borrow of the manager for as long as it lives. The manager can
+    /// be used through the guard in the meantime, since it implements [`Deref`](std::ops::Deref)
+    /// and [`DerefMut`](std::ops::DerefMut) to [`PluginManager`]. Only one scoped plugin can be
+    /// loaded at a time this way, use [`PluginGuard::keep`] to keep the plugin loaded instead.
     ///
-    /// - `E`: The [`ExtensionPoint`] type
+    /// # Errors
     ///
-    /// # Returns
+    /// Returns the same errors as [`load_plugin`](Self::load_plugin). No guard is created in that
+    /// case.
     ///
-    /// A vector of tuples containing mutable references to [`HookID`](crate::hook::HookID)s and hooks registered for the [`ExtensionPoint`]
-    /// from enabled plugins.
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`load_plugin`](Self::load_plugin).
     ///
     /// # Examples
     ///
     /// ```
-    /// use steckrs::{extension_point, simple_plugin, PluginManager};
-    ///
-    /// extension_point!(
-    ///     Logger: LoggerTrait;
-    ///     fn log(&self, message: &str);
-    /// );
-    ///
-    /// struct ConsoleLogger;
-    /// impl LoggerTrait for ConsoleLogger {
-    ///     fn log(&self, message: &str) {
-    ///         // In a real implementation, this would print to console
-    ///     }
-    /// }
+    /// use steckrs::{PluginManager, simple_plugin};
     ///
     /// simple_plugin!(
-    ///     LoggerPlugin,
-    ///     "logger_plugin",
-    ///     "Basic logging plugin",
-    ///     hooks: [(Logger, ConsoleLogger)]
+    ///     TemporaryPlugin,
+    ///     "temporary_plugin",
+    ///     "Only needed for a moment",
+    ///     hooks: []
     /// );
     ///
     /// let mut manager = PluginManager::new();
-    /// manager.load_plugin(Box::new(LoggerPlugin::new())).unwrap();
-    /// manager.enable_plugin(LoggerPlugin::ID).unwrap();
+    /// {
+    ///     let mut guard = manager.load_scoped(Box::new(TemporaryPlugin::new())).unwrap();
+    ///     guard.enable_plugin(TemporaryPlugin::ID).unwrap();
+    ///     assert!(guard.get_plugin(TemporaryPlugin::ID).is_some());
+    /// } // the plugin is unloaded here
     ///
-    /// // Get all enabled hooks for the Logger extension point
-    /// let hooks = manager.get_enabled_hooks_by_ep::<Logger>();
-    /// assert_eq!(hooks.len(), 1);
-    ///
-    /// // Use the hook
-    /// for (id, hook) in hooks {
-    ///     assert_eq!(id.plugin_id, "logger_plugin");
-    ///     hook.inner().log("Hello from logger!");
-    /// }
+    /// assert!(manager.get_plugin(TemporaryPlugin::ID).is_none());
     /// ```
-    #[must_use]
-    pub fn get_enabled_hooks_by_ep_mut<E: ExtensionPoint>(
-        &mut self,
-    ) -> Vec<(&hook::HookID, &mut hook::Hook<E>)> {
-        let enabled_ids: Vec<PluginID> = self
-            .plugins
-            .iter()
-            .filter_map(|(id, plug)| if plug.is_enabled() { Some(*id) } else { None })
-            .collect();
-        self.hook_registry_mut()
+    pub fn load_scoped(&mut self, plugin: Box<dyn Plugin>) -> PluginResult<PluginGuard<'_>> {
+        let plugin_id = plugin.id();
+        self.load_plugin(plugin)?;
+        Ok(PluginGuard {
+            manager: self,
+            plugin_id: Some(plugin_id),
+        })
+    }
+
+    /// Loads multiple plugins, ordered by [load phase](Plugin::load_phase) and dependencies.
+    ///
+    /// Plugins are loaded with [`load_plugin`](Self::load_plugin) in ascending
+    /// [`load_phase`](Plugin::load_phase). Within a phase, a plugin is loaded after the plugins of
+    /// the same phase and batch it [depends on](Plugin::dependencies). Otherwise, and for
+    /// dependency cycles, the plugins keep the order in which they were given.
+    ///
+    /// A plugin failing to load does not stop the others from being loaded.
+    ///
+    /// # Returns
+    ///
+    /// The IDs of the plugins in the order they were loaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BulkError`] with every plugin that failed to load, in the order they were
+    /// loaded. The other plugins are loaded anyway.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`load_plugin`](Self::load_plugin).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{Plugin, PluginID, PluginManager, error::PluginResult, hook::HookRegistry};
+    ///
+    /// #[derive(Debug)]
+    /// struct LayeredPlugin {
+    ///     id: PluginID,
+    ///     phase: u8,
+    ///     dependencies: Vec<PluginID>,
+    /// }
+    ///
+    /// impl Plugin for LayeredPlugin {
+    ///     fn id(&self) -> PluginID {
+    ///         self.id
+    ///     }
+    ///     fn description(&self) -> &str {
+    ///         "A plugin in some layer"
+    ///     }
+    ///     fn is_enabled(&self) -> bool {
+    ///         false
+    ///     }
+    ///     fn enable(&mut self) {}
+    ///     fn disable(&mut self) {}
+    ///     fn register_hooks(&self, _registry: &mut HookRegistry) -> PluginResult<()> {
+    ///         Ok(())
+    ///     }
+    ///     fn load_phase(&self) -> u8 {
+    ///         self.phase
+    ///     }
+    ///     fn dependencies(&self) -> &[PluginID] {
+    ///         &self.dependencies
+    ///     }
+    /// }
+    ///
+    /// let plugins: Vec<Box<dyn Plugin>> = vec![
+    ///     Box::new(LayeredPlugin { id: "ui", phase: 200, dependencies: vec![] }),
+    ///     Box::new(LayeredPlugin { id: "sync", phase: 100, dependencies: vec!["network"] }),
+    ///     Box::new(LayeredPlugin { id: "network", phase: 100, dependencies: vec![] }),
+    ///     Box::new(LayeredPlugin { id: "core", phase: 0, dependencies: vec![] }),
+    /// ];
+    ///
+    /// let mut manager = PluginManager::new();
+    /// let order = manager.load_plugins(plugins).unwrap();
+    /// assert_eq!(order, ["core", "network", "sync", "ui"]);
+    /// ```
+    pub fn load_plugins(
+        &mut self,
+        mut plugins: Vec<Box<dyn Plugin>>,
+    ) -> Result<Vec<PluginID>, BulkError> {
+        // stable, so plugins of the same phase keep their order
+        plugins.sort_by_key(|plugin| plugin.load_phase());
+
+        let mut order = Vec::with_capacity(plugins.len());
+        let mut errors = BulkError::new();
+        while !plugins.is_empty() {
+            let phase = plugins[0].load_phase();
+            let phase_len = plugins
+                .iter()
+                .take_while(|plugin| plugin.load_phase() == phase)
+                .count();
+            // the first plugin that does not wait for another plugin of its phase, or the first
+            // plugin at all if there is a cycle
+            let next = (0..phase_len)
+                .find(|&i| {
+                    plugins[i].dependencies().iter().all(|dependency| {
+                        !plugins[..phase_len]
+                            .iter()
+                            .any(|other| other.id() == *dependency)
+                    })
+                })
+                .unwrap_or(0);
+
+            let plugin = plugins.remove(next);
+            let id = plugin.id();
+            order.push(id);
+            if let Err(e) = self.load_plugin(plugin) {
+                errors.push(id, e);
+            }
+        }
+        errors.into_result().map(|()| order)
+    }
+
+    /// Internal helper that checks the version of a plugin against the requirement of the host,
+    /// and the [`dependency_requirements`](Plugin::dependency_requirements) of a plugin against
+    /// the loaded plugins.
+    #[cfg(feature = "semver")]
+    fn check_version_requirements(&self, plugin: &dyn Plugin) -> PluginResult<()> {
+        if let Some(required) = self.version_requirements.get(plugin.id()) {
+            let satisfied = semver::Version::parse(plugin.version())
+                .is_ok_and(|version| required.matches(&version));
+            if !satisfied {
+                return Err(PluginError::IncompatibleVersion {
+                    id: plugin.id().into(),
+                    found: plugin.version().to_string(),
+                    required: required.to_string(),
+                });
+            }
+        }
+        for (dependency, required) in plugin.dependency_requirements() {
+            let Some(loaded) = self.plugins.get(dependency) else {
+                continue;
+            };
+            let satisfied = match (
+                semver::VersionReq::parse(required),
+                semver::Version::parse(loaded.version()),
+            ) {
+                (Ok(req), Ok(version)) => req.matches(&version),
+                _ => false,
+            };
+            if !satisfied {
+                return Err(PluginError::VersionConflict {
+                    plugin: plugin.id().into(),
+                    dependency: (*dependency).into(),
+                    required: (*required).to_string(),
+                    found: loaded.version().to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Internal helper that checks the version requirements of a plugin, which is not possible
+    /// without the `semver` feature.
+    #[cfg(not(feature = "semver"))]
+    #[allow(clippy::unnecessary_wraps, clippy::unused_self)]
+    fn check_version_requirements(&self, _plugin: &dyn Plugin) -> PluginResult<()> {
+        Ok(())
+    }
+
+    /// Requires plugins with the given ID to have a [version](Plugin::version) matching `req`.
+    ///
+    /// `req` is a [`semver::VersionReq`], like `"^1.2"` or `">=0.3, <0.5"`. The requirement is
+    /// checked whenever a plugin with this ID is loaded. If the version of the plugin does not
+    /// satisfy it, or is not a valid semantic version, loading fails with
+    /// [`PluginError::IncompatibleVersion`]. Plugins that are already loaded are not checked.
+    ///
+    /// Setting a requirement for an ID that already has one replaces the old requirement.
+    ///
+    /// This is only available with the `semver` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`semver::Error`] if `req` is not a valid version requirement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{Plugin, PluginID, PluginManager, error::{PluginError, PluginResult}, hook::HookRegistry};
+    ///
+    /// #[derive(Debug)]
+    /// struct Renderer {
+    ///     version: &'static str,
+    /// }
+    ///
+    /// impl Plugin for Renderer {
+    ///     fn id(&self) -> PluginID {
+    ///         "renderer"
+    ///     }
+    ///     fn description(&self) -> &str {
+    ///         "Renders things"
+    ///     }
+    ///     fn is_enabled(&self) -> bool {
+    ///         true
+    ///     }
+    ///     fn enable(&mut self) {}
+    ///     fn disable(&mut self) {}
+    ///     fn register_hooks(&self, _registry: &mut HookRegistry) -> PluginResult<()> {
+    ///         Ok(())
+    ///     }
+    ///     fn version(&self) -> &str {
+    ///         self.version
+    ///     }
+    /// }
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.require_version("renderer", "^2.0").unwrap();
+    ///
+    /// let result = manager.load_plugin(Box::new(Renderer { version: "1.4.0" }));
+    /// assert!(matches!(result, Err(PluginError::IncompatibleVersion { .. })));
+    ///
+    /// manager.load_plugin(Box::new(Renderer { version: "2.1.0" })).unwrap();
+    ///
+    /// assert!(manager.require_version("renderer", "not a requirement").is_err());
+    /// ```
+    #[cfg(feature = "semver")]
+    pub fn require_version(&mut self, id: PluginID, req: &str) -> Result<(), semver::Error> {
+        let req = semver::VersionReq::parse(req)?;
+        self.version_requirements.insert(id, req);
+        Ok(())
+    }
+
+    /// Internal helper that stores a plugin under `id` after it was initialized successfully.
+    fn store_loaded_plugin(&mut self, id: PluginID, plugin: Box<dyn Plugin>) {
+        self.plugins.insert(id, plugin);
+        self.failed_loads
+            .retain(|(failed_id, _)| failed_id.id() != id);
+        self.bump_generation();
+    }
+
+    /// Internal helper to remember why a plugin could not be loaded.
+    ///
+    /// Only the last failure per plugin is kept, and at most
+    /// [`MAX_FAILED_LOADS`](Self::MAX_FAILED_LOADS) records are kept overall.
+    fn record_failed_load(&mut self, id: PluginID, e: PluginError) {
+        self.failed_loads
+            .retain(|(failed_id, _)| failed_id.id() != id);
+        if self.failed_loads.len() >= Self::MAX_FAILED_LOADS {
+            self.failed_loads.remove(0);
+        }
+        self.failed_loads.push((id.into(), e));
+    }
+
+    /// Gets the records of plugins that failed to load.
+    ///
+    /// When [`load_plugin`](Self::load_plugin) fails to load a plugin, the error is recorded here
+    /// together with the [`PluginID`], so that it can be shown later, e.g. in a user interface.
+    ///
+    /// - Only the last failure per plugin is kept
+    /// - A record is removed once a plugin with the same ID is loaded successfully
+    /// - Trying to load a plugin that is already loaded is not recorded
+    /// - At most [`MAX_FAILED_LOADS`](Self::MAX_FAILED_LOADS) records are kept, the oldest records
+    ///   are dropped first
+    ///
+    /// The records are ordered from oldest to newest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{Plugin, PluginManager, PluginStatus, error::PluginResult, hook::HookRegistry};
+    ///
+    /// #[derive(Debug)]
+    /// struct NeedsConfigPlugin;
+    ///
+    /// impl Plugin for NeedsConfigPlugin {
+    ///     fn id(&self) -> steckrs::PluginID {
+    ///         "needs_config"
+    ///     }
+    ///     fn description(&self) -> &str {
+    ///         "Does not work without a configuration"
+    ///     }
+    ///     fn is_enabled(&self) -> bool {
+    ///         false
+    ///     }
+    ///     fn enable(&mut self) {}
+    ///     fn disable(&mut self) {}
+    ///     fn register_hooks(&self, _registry: &mut HookRegistry) -> PluginResult<()> {
+    ///         Ok(())
+    ///     }
+    ///     fn status(&self) -> PluginStatus {
+    ///         PluginStatus::Failed("no configuration found".into())
+    ///     }
+    /// }
+    ///
+    /// let mut manager = PluginManager::new();
+    /// assert!(manager.load_plugin(Box::new(NeedsConfigPlugin)).is_err());
+    ///
+    /// let failed = manager.failed_loads();
+    /// assert_eq!(failed.len(), 1);
+    /// assert_eq!(failed[0].0.id(), "needs_config");
+    /// println!("{} failed to load: {}", failed[0].0, failed[0].1);
+    /// ```
+    #[must_use]
+    pub fn failed_loads(&self) -> &[(PluginIDOwned, PluginError)] {
+        &self.failed_loads
+    }
+
+    /// Internal helper that registers the hooks of a plugin, calls its `on_load` method and checks
+    /// its status.
+    ///
+    /// The plugin is initialized as `id`, which is the ID it will be stored under.
+    ///
+    /// This neither stores the plugin in the manager nor cleans up on failure.
+    fn initialize_plugin(&mut self, id: PluginID, plugin: &mut dyn Plugin) -> PluginResult<()> {
+        // register the hooks
+        Self::register_plugin_hooks(&mut self.hook_registry, &self.plugin_features, id, plugin)?;
+        self.finish_loading(id, plugin)
+    }
+
+    /// Internal helper that initializes a plugin that was loaded before, like
+    /// [`initialize_plugin`](Self::initialize_plugin), but registers its hooks even for closed
+    /// extension points.
+    fn restore_plugin(&mut self, id: PluginID, plugin: &mut dyn Plugin) -> PluginResult<()> {
+        let features = &self.plugin_features;
+        self.hook_registry.with_extension_points_open(|registry| {
+            Self::register_plugin_hooks(registry, features, id, &*plugin)
+        })?;
+        self.finish_loading(id, plugin)
+    }
+
+    /// Internal helper that calls the `on_load` method of a plugin whose hooks are registered,
+    /// and checks its status.
+    fn finish_loading(&mut self, id: PluginID, plugin: &mut dyn Plugin) -> PluginResult<()> {
+        // Load the plugin
+        plugin.on_load_ctx(&mut Self::plugin_context(
+            &mut self.hook_registry,
+            &self.plugin_features,
+            id,
+        ))?;
+        // The plugin may have loaded, but still be broken
+        if let PluginStatus::Failed(reason) = plugin.status() {
+            return Err(PluginError::LoadedButFailed {
+                plugin: id.into(),
+                reason,
+            });
+        }
+        Ok(())
+    }
+
+    /// Internal helper that creates the [`PluginContext`] for the plugin stored under `id`, with
+    /// the features enabled for it.
+    fn plugin_context<'a>(
+        registry: &'a mut HookRegistry,
+        features: &'a HashMap<PluginID, HashSet<String>>,
+        id: PluginID,
+    ) -> PluginContext<'a> {
+        PluginContext {
+            plugin_id: id,
+            registry,
+            features: features.get(id),
+        }
+    }
+
+    /// Internal helper that registers the hooks of a plugin that is stored under `id`.
+    ///
+    /// If `id` is not the ID of the plugin, it is an instance, see
+    /// [`load_instance`](Self::load_instance). The hooks are then registered in a separate
+    /// registry first, and moved over with `id` as their [`PluginID`].
+    fn register_plugin_hooks(
+        registry: &mut HookRegistry,
+        features: &HashMap<PluginID, HashSet<String>>,
+        id: PluginID,
+        plugin: &dyn Plugin,
+    ) -> PluginResult<()> {
+        if plugin.id() == id {
+            return plugin.register_hooks_ctx(&mut Self::plugin_context(registry, features, id));
+        }
+
+        let mut own_registry = HookRegistry::new();
+        plugin.register_hooks_ctx(&mut Self::plugin_context(&mut own_registry, features, id))?;
+        let hook_ids: Vec<hook::HookID> = own_registry
+            .get_by_filter(|_| true)
+            .into_iter()
+            .map(|(hook_id, _hook)| hook_id.clone())
+            .collect();
+        for hook_id in hook_ids {
+            let Some(hook) = own_registry.deregister(&hook_id) else {
+                continue;
+            };
+            let instance_hook_id = hook::HookID {
+                plugin_id: id,
+                ..hook_id
+            };
+            registry.register_erased(&instance_hook_id, hook)?;
+        }
+        Ok(())
+    }
+
+    /// Internal helper to handle errors during plugin loading.
+    ///
+    /// If a plugin fails during loading, this will attempt to clean up
+    /// by unloading the plugin.
+    fn handle_error_during_load(
+        &mut self,
+        plugin_id: PluginID,
+        e: &PluginError,
+        plugin: Box<dyn Plugin>,
+    ) {
+        error!("Could not load plugin {plugin_id}: {e}");
+        warn!("Trying to unload the plugin again... Will crash if this fails");
+        // the plugin needs to be known to the manager to be unloaded
+        self.plugins.insert(plugin_id, plugin);
+        self.unload_plugin(plugin_id)
+            .expect("Could not unload bad plugin again");
+    }
+
+    /// Unloads a plugin by ID.
+    ///
+    /// This will:
+    /// 1. Call the plugin's `on_unload` method for cleanup
+    /// 2. Remove all hooks registered by the plugin
+    /// 3. Remove the plugin from the manager
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError`] if:
+    /// - The manager is [locked](Self::lock) ([`PluginError::Locked`])
+    /// - The plugin's [`on_unload`](Plugin::on_unload) method fails, in which case the plugin
+    ///   and its hooks are still removed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     ExamplePlugin,
+    ///     "example_plugin",
+    ///     "An example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    /// manager.unload_plugin("example_plugin").unwrap();
+    /// assert!(manager.get_plugin("example_plugin").is_none());
+    /// ```
+    pub fn unload_plugin(&mut self, id: PluginID) -> PluginResult<()> {
+        self.check_unlocked()?;
+        self.remove_plugin(id)
+    }
+
+    /// Internal helper that unloads a plugin like [`unload_plugin`](Self::unload_plugin), even if
+    /// the manager is locked.
+    fn remove_plugin(&mut self, id: PluginID) -> PluginResult<()> {
+        let _span = info_span!("unload_plugin", plugin_id = id).entered();
+        if let Some(mut plugin) = self.plugins.remove(id) {
+            // Call on_unload for cleanup
+            let result = plugin.on_unload_ctx(&mut Self::plugin_context(
+                &mut self.hook_registry,
+                &self.plugin_features,
+                id,
+            ));
+
+            // Remove all hooks registered by this plugin, even if it failed to clean up, since
+            // nothing could remove them later
+            self.hook_registry.deregister_hooks_for_plugin(id);
+            self.bump_generation();
+            result?;
+        }
+        Ok(())
+    }
+
+    /// Clears the [`HookRegistry`] and registers the hooks of all loaded plugins again.
+    ///
+    /// [`BoxedHook`](hook::BoxedHook)s cannot be cloned, so the registry cannot be restored from a
+    /// snapshot, but it can be rebuilt from the plugins. This calls
+    /// [`register_hooks`](Plugin::register_hooks) of every loaded plugin, ordered by
+    /// [`PluginID`]. Hooks that were registered in other ways, like directly through
+    /// [`hook_registry_mut`](Self::hook_registry_mut) or in
+    /// [`on_load_ctx`](Plugin::on_load_ctx), are gone afterwards. Settings of the registry are
+    /// kept, see [`HookRegistry::clear`]. Hooks are registered again even if their extension point
+    /// is [closed](HookRegistry::close_extension_point).
+    ///
+    /// Hooks that are registered again under the same [`HookID`](hook::HookID) keep their
+    /// [enabled state](HookRegistry::set_hook_enabled) and the name given with
+    /// [`set_hook_name`](HookRegistry::set_hook_name).
+    ///
+    /// If a plugin fails to register its hooks, the hooks it did register are removed again, and
+    /// the remaining plugins are still rebuilt. The plugin stays loaded without hooks, and the
+    /// failure is only reported in the returned [`BulkError`], not in
+    /// [`failed_loads`](Self::failed_loads).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BulkError`] with every plugin that failed to register its hooks. If the manager
+    /// is [locked](Self::lock), nothing is changed and every loaded plugin is reported with
+    /// [`PluginError::Locked`]. If no plugin is loaded, the [`BulkError`] holds a single
+    /// [`PluginError::Locked`] for the empty [`PluginID`] instead, so that the call still fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager, hook::HookID};
+    ///
+    /// extension_point!(
+    ///     Greeter: GreeterTrait;
+    ///     fn greet(&self, name: &str) -> String;
+    /// );
+    ///
+    /// struct EnglishGreeter;
+    /// impl GreeterTrait for EnglishGreeter {
+    ///     fn greet(&self, name: &str) -> String {
+    ///         format!("Hello, {}!", name)
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     HelloPlugin,
+    ///     "hello_plugin",
+    ///     "A simple greeting plugin",
+    ///     hooks: [(Greeter, EnglishGreeter)]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(HelloPlugin::new())).unwrap();
+    ///
+    /// // hooks keep their settings
+    /// let id = HookID::for_ep::<Greeter>(HelloPlugin::ID, None);
+    /// manager.hook_registry_mut().set_hook_enabled(&id, false).unwrap();
+    /// manager.hook_registry_mut().set_hook_name(&id, "Hello");
+    /// manager.rebuild_registry().unwrap();
+    /// assert_eq!(manager.hook_registry().is_hook_enabled(&id), Some(false));
+    /// assert_eq!(manager.hook_registry().get_by_id(&id).unwrap().1.name(), "Hello");
+    ///
+    /// // something removed the hooks behind the back of the plugin
+    /// manager.hook_registry_mut().clear();
+    /// assert!(manager.hook_registry().get_by_extension_point::<Greeter>().is_empty());
+    ///
+    /// manager.rebuild_registry().unwrap();
+    /// assert_eq!(manager.hook_registry().get_by_extension_point::<Greeter>().len(), 1);
+    /// ```
+    pub fn rebuild_registry(&mut self) -> Result<(), BulkError> {
+        if let Err(e) = self.check_unlocked() {
+            let mut ids = self.plugin_ids();
+            ids.sort_unstable();
+            if ids.is_empty() {
+                ids.push("");
+            }
+            return BulkError::from_results(
+                ids.into_iter().map(|id| (id, Err::<(), _>(e.clone()))),
+            );
+        }
+
+        // the settings of single hooks are dropped with the hooks, so remember them
+        let hook_settings: Vec<(hook::HookID, bool, String)> = self
+            .hook_registry
+            .get_by_filter(|_| true)
+            .into_iter()
+            .map(|(id, hook)| (id.clone(), hook.is_enabled(), hook.name().to_string()))
+            .collect();
+        self.hook_registry.clear();
+
+        let mut ids = self.plugin_ids();
+        ids.sort_unstable();
+        let mut errors = BulkError::new();
+        for id in ids {
+            let features = &self.plugin_features;
+            let plugin = self.plugins[id].as_ref();
+            let result = self.hook_registry.with_extension_points_open(|registry| {
+                Self::register_plugin_hooks(registry, features, id, plugin)
+            });
+            if let Err(e) = result {
+                error!("Could not register the hooks of plugin {id} again: {e}");
+                self.hook_registry.deregister_hooks_for_plugin(id);
+                errors.push(id, e);
+            }
+        }
+        for (id, enabled, name) in hook_settings {
+            if self.hook_registry.set_hook_enabled(&id, enabled).is_err() {
+                // the hook was not registered again
+                continue;
+            }
+            if self
+                .hook_registry
+                .get_by_id(&id)
+                .is_some_and(|(_id, hook)| hook.name() != name)
+            {
+                self.hook_registry.set_hook_name(&id, name);
+            }
+        }
+        self.bump_generation();
+
+        errors.into_result()
+    }
+
+    /// Replaces a loaded plugin with a new plugin that has the same ID.
+    ///
+    /// This is a single operation that:
+    /// 1. Calls the old plugin's `on_unload` method and removes its hooks
+    /// 2. Registers the new plugin's hooks and calls its `on_load` method, like
+    ///    [`load_plugin`](Self::load_plugin)
+    /// 3. Enables the new plugin if the old plugin was enabled, disables it otherwise
+    ///
+    /// The old plugin is returned, so that it can be inspected or reused. Note that its
+    /// [`on_unload`](Plugin::on_unload) method has already been called at that point.
+    ///
+    /// Since the hooks of a plugin are identified by the [`PluginID`], the new plugin's hooks keep
+    /// the same [`HookID`](hook::HookID)s as long as it registers the same hooks as the old plugin.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError`] if:
+    /// - The manager is [locked](Self::lock) ([`PluginError::Locked`])
+    /// - No plugin with the same ID is loaded ([`PluginError::NotFound`])
+    /// - The old plugin's [`on_unload`](Plugin::on_unload) method fails, in which case nothing
+    ///   is changed
+    /// - Loading the new plugin fails (see [`load_plugin`](Self::load_plugin)), in which case
+    ///   the new plugin is cleaned up and the old plugin is loaded again. The hooks of the old
+    ///   plugin are registered again even if their extension point was
+    ///   [closed](HookRegistry::close_extension_point) in the meantime. If loading the old plugin
+    ///   fails as well, it is dropped, and its error is recorded in
+    ///   [`failed_loads`](Self::failed_loads).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     VariantA,
+    ///     "example_plugin",
+    ///     "Variant A of the example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// simple_plugin!(
+    ///     VariantB,
+    ///     "example_plugin",
+    ///     "Variant B of the example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(VariantA::new())).unwrap();
+    /// manager.enable_plugin("example_plugin").unwrap();
+    ///
+    /// let old = manager.swap_plugin(Box::new(VariantB::new())).unwrap();
+    /// assert_eq!(old.description(), VariantA::DESCRIPTION);
+    ///
+    /// let new = manager.get_plugin("example_plugin").unwrap();
+    /// assert_eq!(new.description(), VariantB::DESCRIPTION);
+    /// assert!(new.is_enabled());
+    /// ```
+    pub fn swap_plugin(&mut self, mut new: Box<dyn Plugin>) -> PluginResult<Box<dyn Plugin>> {
+        self.check_unlocked()?;
+        let id = new.id();
+        let Some(mut old) = self.plugins.remove(id) else {
+            return Err(PluginError::NotFound(id.into()));
+        };
+
+        if let Err(e) = old.on_unload_ctx(&mut Self::plugin_context(
+            &mut self.hook_registry,
+            &self.plugin_features,
+            id,
+        )) {
+            self.plugins.insert(id, old);
+            return Err(e);
+        }
+        self.hook_registry.deregister_hooks_for_plugin(id);
+        // if something goes wrong now, the registry already changed
+        self.bump_generation();
+
+        if let Err(e) = self.initialize_plugin(id, new.as_mut()) {
+            error!("Could not load plugin {id} as replacement: {e}");
+            if let Err(unload_err) = new.on_unload_ctx(&mut Self::plugin_context(
+                &mut self.hook_registry,
+                &self.plugin_features,
+                id,
+            )) {
+                warn!("Could not unload the failed replacement for plugin {id}: {unload_err}");
+            }
+            self.hook_registry.deregister_hooks_for_plugin(id);
+            warn!("Trying to load the replaced plugin {id} again");
+            if let Err(restore_err) = self.restore_plugin(id, old.as_mut()) {
+                error!("Could not load the replaced plugin {id} again: {restore_err}");
+                self.hook_registry.deregister_hooks_for_plugin(id);
+                self.record_failed_load(id, restore_err);
+                return Err(e);
+            }
+            self.plugins.insert(id, old);
+            return Err(e);
+        }
+
+        if old.is_enabled() {
+            new.enable();
+        } else {
+            new.disable();
+        }
+        self.plugins.insert(id, new);
+
+        Ok(old)
+    }
+
+    /// Reloads a plugin with a new instance that has the same ID.
+    ///
+    /// This works like [`swap_plugin`](Self::swap_plugin), but drops the old plugin instead of
+    /// returning it. The hooks of the old plugin are removed before the new plugin registers its
+    /// hooks, so the new plugin can register the same [`HookID`](hook::HookID)s. The new plugin
+    /// is enabled if the old plugin was enabled.
+    ///
+    /// Unlike [`load_plugin`](Self::load_plugin), this only works for plugins that are already
+    /// loaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`swap_plugin`](Self::swap_plugin), in particular
+    /// [`PluginError::NotFound`] if no plugin with the same ID is loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, error::PluginError, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     ExamplePlugin,
+    ///     "example_plugin",
+    ///     "An example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// let result = manager.reload_plugin(Box::new(ExamplePlugin::new()));
+    /// assert!(matches!(result, Err(PluginError::NotFound(_))));
+    ///
+    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    /// manager.enable_plugin(ExamplePlugin::ID).unwrap();
+    /// manager.reload_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    ///
+    /// assert_eq!(manager.plugin_is_enabled(ExamplePlugin::ID), Some(true));
+    /// ```
+    pub fn reload_plugin(&mut self, plugin: Box<dyn Plugin>) -> PluginResult<()> {
+        self.swap_plugin(plugin).map(drop)
+    }
+
+    /// Polls a [`ReloadSource`] and reloads every plugin it returns.
+    ///
+    /// Each plugin is reloaded with [`swap_plugin`](Self::swap_plugin), the old versions are
+    /// dropped. The reloads are applied in the order returned by
+    /// [`poll`](ReloadSource::poll), and a failed reload does not stop the following ones.
+    ///
+    /// # Returns
+    ///
+    /// The number of polled plugins.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BulkError`] with the error of [`swap_plugin`](Self::swap_plugin) for every
+    /// plugin that could not be reloaded, in the order they were polled. If the [`PluginID`]
+    /// given by the source does not match the [`id`](Plugin::id) of the new plugin, nothing is
+    /// reloaded for that entry and [`PluginError::NotFound`] is reported for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{Plugin, PluginID, PluginManager, ReloadSource, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     VariantA,
+    ///     "example_plugin",
+    ///     "Variant A of the example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// simple_plugin!(
+    ///     VariantB,
+    ///     "example_plugin",
+    ///     "Variant B of the example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// struct OneShot(Option<Box<dyn Plugin>>);
+    ///
+    /// impl ReloadSource for OneShot {
+    ///     fn poll(&mut self) -> Vec<(PluginID, Box<dyn Plugin>)> {
+    ///         self.0.take().map(|p| (p.id(), p)).into_iter().collect()
+    ///     }
+    /// }
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(VariantA::new())).unwrap();
+    ///
+    /// let mut source = OneShot(Some(Box::new(VariantB::new())));
+    /// assert_eq!(manager.apply_reloads(&mut source).unwrap(), 1);
+    /// assert_eq!(
+    ///     manager.get_plugin("example_plugin").unwrap().description(),
+    ///     VariantB::DESCRIPTION
+    /// );
+    ///
+    /// // nothing left to reload
+    /// assert_eq!(manager.apply_reloads(&mut source).unwrap(), 0);
+    /// ```
+    pub fn apply_reloads(&mut self, source: &mut dyn ReloadSource) -> Result<usize, BulkError> {
+        let reloads = source.poll();
+        let count = reloads.len();
+        let results: Vec<(PluginID, PluginResult<()>)> = reloads
+            .into_iter()
+            .map(|(id, plugin)| {
+                if plugin.id() != id {
+                    warn!(
+                        "Reload source returned plugin {} for the ID {id}, skipping",
+                        plugin.id()
+                    );
+                    return (id, Err(PluginError::NotFound(id.into())));
+                }
+                (id, self.swap_plugin(plugin).map(drop))
+            })
+            .collect();
+        BulkError::from_results(results).map(|()| count)
+    }
+
+    /// Loads the plugins listed in a [`PluginManifest`], creating them with a [`PluginFactory`].
+    ///
+    /// For each entry of the manifest, in order, the plugin is created by the factory, loaded
+    /// with [`load_plugin`](Self::load_plugin), and then enabled or disabled as the entry says.
+    /// A failure for one entry does not stop the following entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BulkError`] with the error of every entry that failed, in the order of the
+    /// manifest:
+    /// - [`PluginError::NotFound`] if the factory has no constructor for the ID, or the
+    ///   constructed plugin has a different ID
+    /// - The error of [`load_plugin`](Self::load_plugin) if loading fails
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{
+    ///     ManifestEntry, PluginFactory, PluginManager, PluginManifest, error::PluginError,
+    ///     simple_plugin,
+    /// };
+    ///
+    /// simple_plugin!(
+    ///     PluginA,
+    ///     "plugin_a",
+    ///     "The first plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// simple_plugin!(
+    ///     PluginB,
+    ///     "plugin_b",
+    ///     "The second plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut factory = PluginFactory::new();
+    /// factory.register(PluginA::ID, || Box::new(PluginA::new()));
+    /// factory.register(PluginB::ID, || Box::new(PluginB::new()));
+    ///
+    /// let manifest = PluginManifest {
+    ///     plugins: vec![
+    ///         ManifestEntry { id: PluginA::ID.into(), enabled: true },
+    ///         ManifestEntry { id: PluginB::ID.into(), enabled: false },
+    ///         ManifestEntry { id: "unknown_plugin".into(), enabled: true },
+    ///     ],
+    /// };
+    ///
+    /// let mut manager = PluginManager::new();
+    /// let err = manager.load_from_manifest(&manifest, &factory).unwrap_err();
+    ///
+    /// assert_eq!(err.len(), 1);
+    /// assert_eq!(err.errors()[0].0.id(), "unknown_plugin");
+    /// assert!(matches!(err.errors()[0].1, PluginError::NotFound(_)));
+    /// assert_eq!(manager.plugin_is_enabled(PluginA::ID), Some(true));
+    /// assert_eq!(manager.plugin_is_enabled(PluginB::ID), Some(false));
+    /// ```
+    pub fn load_from_manifest(
+        &mut self,
+        manifest: &PluginManifest,
+        factory: &PluginFactory,
+    ) -> Result<(), BulkError> {
+        let results: Vec<(PluginID, PluginResult<()>)> = manifest
+            .plugins
+            .iter()
+            .map(|entry| {
+                let id = entry.id.id();
+                (id, self.load_manifest_entry(id, entry.enabled, factory))
+            })
+            .collect();
+        BulkError::from_results(results)
+    }
+
+    /// Internal helper that creates, loads and enables or disables the plugin of one
+    /// [`ManifestEntry`].
+    fn load_manifest_entry(
+        &mut self,
+        id: PluginID,
+        enabled: bool,
+        factory: &PluginFactory,
+    ) -> PluginResult<()> {
+        let Some(plugin) = factory.create(id) else {
+            warn!("No constructor for plugin {id} in the factory, skipping");
+            return Err(PluginError::NotFound(id.into()));
+        };
+        if plugin.id() != id {
+            warn!(
+                "Factory created plugin {} for the ID {id}, skipping",
+                plugin.id()
+            );
+            return Err(PluginError::NotFound(id.into()));
+        }
+        self.load_plugin(plugin)?;
+        if enabled {
+            self.enable_plugin(id)
+        } else {
+            self.disable_plugin(id)
+        }
+    }
+
+    /// Gets a reference to a plugin by ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     ExamplePlugin,
+    ///     "example_plugin",
+    ///     "An example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    ///
+    /// let plugin = manager.get_plugin("example_plugin");
+    /// assert!(plugin.is_some());
+    /// assert_eq!(plugin.unwrap().id(), "example_plugin");
+    /// ```
+    #[must_use]
+    pub fn get_plugin(&self, id: PluginID) -> Option<&dyn Plugin> {
+        self.plugins.get(id).map(std::convert::AsRef::as_ref)
+    }
+
+    /// Gets a mutable reference to a plugin by ID.
+    ///
+    /// This can be used to modify a plugin's state after it's been loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     ExamplePlugin,
+    ///     "example_plugin",
+    ///     "An example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    ///
+    /// let plugin = manager.get_plugin_mut("example_plugin");
+    /// assert!(plugin.is_some());
+    /// ```
+    #[must_use]
+    pub fn get_plugin_mut(&mut self, id: PluginID) -> Option<&mut dyn Plugin> {
+        self.plugins.get_mut(id).map(std::convert::AsMut::as_mut)
+    }
+
+    /// Gets a reference to a plugin by ID, as its concrete type.
+    ///
+    /// This gives access to state that is specific to a plugin type, which the [`Plugin`] trait
+    /// does not expose. It is a shortcut for [`get_plugin`](Self::get_plugin) followed by a
+    /// downcast through the [`Any`] supertrait of [`Plugin`].
+    ///
+    /// Returns [`None`] if no plugin with that ID is loaded, or if it is not a `P`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     ExamplePlugin,
+    ///     "example_plugin",
+    ///     "An example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// simple_plugin!(
+    ///     OtherPlugin,
+    ///     "other_plugin",
+    ///     "Another plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    ///
+    /// let example: &ExamplePlugin = manager.get_plugin_as(ExamplePlugin::ID).unwrap();
+    /// assert!(!example.enabled);
+    ///
+    /// assert!(manager.get_plugin_as::<OtherPlugin>(ExamplePlugin::ID).is_none());
+    /// assert!(manager.get_plugin_as::<OtherPlugin>(OtherPlugin::ID).is_none());
+    /// ```
+    #[must_use]
+    pub fn get_plugin_as<P: Plugin>(&self, id: PluginID) -> Option<&P> {
+        self.get_plugin(id)?.as_any().downcast_ref()
+    }
+
+    /// Gets a mutable reference to a plugin by ID, as its concrete type.
+    ///
+    /// Like [`get_plugin_as`](Self::get_plugin_as), but with mutable access.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     ExamplePlugin,
+    ///     "example_plugin",
+    ///     "An example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    ///
+    /// let example = manager.get_plugin_as_mut::<ExamplePlugin>(ExamplePlugin::ID).unwrap();
+    /// example.enabled = true;
+    ///
+    /// assert_eq!(manager.plugin_is_enabled(ExamplePlugin::ID), Some(true));
+    /// ```
+    #[must_use]
+    pub fn get_plugin_as_mut<P: Plugin>(&mut self, id: PluginID) -> Option<&mut P> {
+        self.get_plugin_mut(id)?.as_any_mut().downcast_mut()
+    }
+
+    /// Checks if a plugin with the given ID is loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     ExamplePlugin,
+    ///     "example_plugin",
+    ///     "An example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// assert!(!manager.is_loaded(ExamplePlugin::ID));
+    /// assert_eq!(manager.loaded_count(), 0);
+    ///
+    /// if !manager.is_loaded(ExamplePlugin::ID) {
+    ///     manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    /// }
+    /// assert!(manager.is_loaded(ExamplePlugin::ID));
+    /// assert_eq!(manager.loaded_count(), 1);
+    /// ```
+    #[must_use]
+    pub fn is_loaded(&self, id: PluginID) -> bool {
+        self.plugins.contains_key(id)
+    }
+
+    /// Returns the number of loaded plugins, enabled or not.
+    #[must_use]
+    pub fn loaded_count(&self) -> usize {
+        self.plugins.len()
+    }
+
+    /// Gets all plugin IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     Plugin1,
+    ///     "plugin1",
+    ///     "First plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// simple_plugin!(
+    ///     Plugin2,
+    ///     "plugin2",
+    ///     "Second plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(Plugin1::new())).unwrap();
+    /// manager.load_plugin(Box::new(Plugin2::new())).unwrap();
+    ///
+    /// let ids = manager.plugin_ids();
+    /// assert_eq!(ids.len(), 2);
+    /// assert!(ids.contains(&"plugin1"));
+    /// assert!(ids.contains(&"plugin2"));
+    /// ```
+    #[must_use]
+    pub fn plugin_ids(&self) -> Vec<PluginID> {
+        self.plugins.keys().copied().collect()
+    }
+
+    /// Gets all plugins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     Plugin1,
+    ///     "plugin1",
+    ///     "First plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(Plugin1::new())).unwrap();
+    ///
+    /// let plugins = manager.plugins();
+    /// assert_eq!(plugins.len(), 1);
+    /// assert_eq!(plugins[0].id(), "plugin1");
+    /// ```
+    #[must_use]
+    pub fn plugins(&self) -> Vec<&dyn Plugin> {
+        self.plugins
+            .values()
+            .map(std::convert::AsRef::as_ref)
+            .collect()
+    }
+
+    /// Gets all plugins, ordered by a comparator.
+    ///
+    /// This is for ordering the plugins by anything they report, like their version or
+    /// description, for example to show them to a user. The sort is stable, but plugins that
+    /// `cmp` considers equal come in no particular order, as the plugins themselves are stored in
+    /// no particular order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     Plugin1,
+    ///     "plugin1",
+    ///     "Zebra support",
+    ///     hooks: []
+    /// );
+    ///
+    /// simple_plugin!(
+    ///     Plugin2,
+    ///     "plugin2",
+    ///     "Antelope support",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(Plugin1::new())).unwrap();
+    /// manager.load_plugin(Box::new(Plugin2::new())).unwrap();
+    ///
+    /// let by_description = manager.sort_plugins_by(|a, b| a.description().cmp(b.description()));
+    /// let ids: Vec<_> = by_description.iter().map(|plugin| plugin.id()).collect();
+    /// assert_eq!(ids, ["plugin2", "plugin1"]);
+    /// ```
+    #[must_use]
+    pub fn sort_plugins_by(
+        &self,
+        cmp: impl Fn(&dyn Plugin, &dyn Plugin) -> std::cmp::Ordering,
+    ) -> Vec<&dyn Plugin> {
+        let mut plugins = self.plugins();
+        plugins.sort_by(|a, b| cmp(*a, *b));
+        plugins
+    }
+
+    /// Calls `f` for every loaded plugin, without allocating.
+    ///
+    /// The plugins are visited in no particular order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     Plugin1,
+    ///     "plugin1",
+    ///     "First plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(Plugin1::new())).unwrap();
+    ///
+    /// let mut descriptions = Vec::new();
+    /// manager.for_each_plugin(|plugin| descriptions.push(plugin.description().to_string()));
+    /// assert_eq!(descriptions, ["First plugin"]);
+    /// ```
+    pub fn for_each_plugin(&self, mut f: impl FnMut(&dyn Plugin)) {
+        for plugin in self.plugins.values() {
+            f(plugin.as_ref());
+        }
+    }
+
+    /// Calls `f` with mutable access to every loaded plugin, without allocating.
+    ///
+    /// The plugins are visited in no particular order. Since `f` may change the plugins, like
+    /// enabling or disabling them, this counts as a change for [`generation`](Self::generation).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     Plugin1,
+    ///     "plugin1",
+    ///     "First plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(Plugin1::new())).unwrap();
+    ///
+    /// manager.for_each_plugin_mut(|plugin| plugin.enable());
+    /// assert_eq!(manager.plugin_is_enabled("plugin1"), Some(true));
+    /// ```
+    pub fn for_each_plugin_mut(&mut self, mut f: impl FnMut(&mut dyn Plugin)) {
+        for plugin in self.plugins.values_mut() {
+            f(plugin.as_mut());
+        }
+        self.bump_generation();
+    }
+
+    /// Gets all enabled plugins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     Plugin1,
+    ///     "plugin1",
+    ///     "First plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// simple_plugin!(
+    ///     Plugin2,
+    ///     "plugin2",
+    ///     "Second plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(Plugin1::new())).unwrap();
+    /// manager.load_plugin(Box::new(Plugin2::new())).unwrap();
+    /// manager.enable_plugin("plugin1").unwrap();
+    ///
+    /// let enabled = manager.enabled_plugins();
+    /// assert_eq!(enabled.len(), 1);
+    /// assert_eq!(enabled[0].id(), "plugin1");
+    /// ```
+    #[must_use]
+    pub fn enabled_plugins(&self) -> Vec<&dyn Plugin> {
+        self.plugins
+            .values()
+            .filter(|p| p.is_enabled())
+            .map(std::convert::AsRef::as_ref)
+            .collect()
+    }
+
+    /// Gets all plugins that advertise a specific capability.
+    ///
+    /// See [`Plugin::capabilities`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{Plugin, PluginManager, error::PluginResult, hook::HookRegistry};
+    ///
+    /// #[derive(Debug)]
+    /// struct CompressionPlugin;
+    ///
+    /// impl Plugin for CompressionPlugin {
+    ///     fn id(&self) -> steckrs::PluginID {
+    ///         "compression"
+    ///     }
+    ///     fn description(&self) -> &str {
+    ///         "Compresses things"
+    ///     }
+    ///     fn is_enabled(&self) -> bool {
+    ///         true
+    ///     }
+    ///     fn enable(&mut self) {}
+    ///     fn disable(&mut self) {}
+    ///     fn register_hooks(&self, _registry: &mut HookRegistry) -> PluginResult<()> {
+    ///         Ok(())
+    ///     }
+    ///     fn capabilities(&self) -> &[&str] {
+    ///         &["gzip", "zstd"]
+    ///     }
+    /// }
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(CompressionPlugin)).unwrap();
+    ///
+    /// assert_eq!(manager.plugins_supporting("zstd").len(), 1);
+    /// assert!(manager.plugins_supporting("brotli").is_empty());
+    /// ```
+    #[must_use]
+    pub fn plugins_supporting(&self, capability: &str) -> Vec<&dyn Plugin> {
+        self.plugins
+            .values()
+            .filter(|p| p.capabilities().contains(&capability))
+            .map(std::convert::AsRef::as_ref)
+            .collect()
+    }
+
+    /// Quickly check if a [`Plugin`] with a specific [`PluginID`] is enabled.
+    ///
+    /// This will return [`None`] if the [`Plugin`] with that [`PluginID`] was not found, otherwise
+    /// `Some(enabled)`, where `enabled` is gotten with [`Plugin::is_enabled`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     Plugin,
+    ///     "plugin",
+    ///     "Some plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(Plugin::new())).unwrap();
+    /// manager.enable_plugin("plugin").unwrap();
+    ///
+    /// assert_eq!(manager.plugin_is_enabled("plugin"), Some(true));
+    /// assert_eq!(manager.plugin_is_enabled("nope"), None);
+    ///
+    /// manager.disable_plugin("plugin").unwrap();
+    ///
+    /// assert_eq!(manager.plugin_is_enabled("plugin"), Some(false));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn plugin_is_enabled(&self, id: PluginID) -> Option<bool> {
+        Some(self.plugins.get(id)?.is_enabled())
+    }
+
+    /// Gets the [`PluginState`] of a [`Plugin`] with a specific [`PluginID`].
+    ///
+    /// This combines whether the plugin is loaded, whether it is enabled and its
+    /// [`status`](Plugin::status) into a single state:
+    ///
+    /// - [`PluginState::Failed`] if no plugin with that ID is loaded, and the last attempt to load
+    ///   it failed (see [`failed_loads`](Self::failed_loads))
+    /// - [`PluginState::Unloaded`] if no plugin with that ID is loaded otherwise
+    /// - [`PluginState::Failed`] if the plugin reports [`PluginStatus::Failed`], regardless of
+    ///   whether it is enabled
+    /// - [`PluginState::Enabled`] if the plugin is enabled
+    /// - [`PluginState::Loaded`] otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, PluginState, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     ExamplePlugin,
+    ///     "example_plugin",
+    ///     "An example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// assert_eq!(manager.plugin_state(ExamplePlugin::ID), PluginState::Unloaded);
+    ///
+    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    /// assert_eq!(manager.plugin_state(ExamplePlugin::ID), PluginState::Loaded);
+    ///
+    /// manager.enable_plugin(ExamplePlugin::ID).unwrap();
+    /// assert_eq!(manager.plugin_state(ExamplePlugin::ID), PluginState::Enabled);
+    /// ```
+    #[must_use]
+    pub fn plugin_state(&self, id: PluginID) -> PluginState {
+        match self.plugins.get(id) {
+            None if self
+                .failed_loads
+                .iter()
+                .any(|(failed_id, _)| failed_id.id() == id) =>
+            {
+                PluginState::Failed
+            }
+            None => PluginState::Unloaded,
+            Some(plugin) if plugin.status().is_failed() => PluginState::Failed,
+            Some(plugin) if plugin.is_enabled() => PluginState::Enabled,
+            Some(_) => PluginState::Loaded,
+        }
+    }
+
+    /// Enables a plugin by ID.
+    ///
+    /// Note that plugins are disabled by default
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError::NotFound`] if no plugin with the given ID is loaded, and a
+    /// [`PluginError::Locked`] if the manager is [locked](Self::lock).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     ExamplePlugin,
+    ///     "example_plugin",
+    ///     "An example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    /// manager.enable_plugin("example_plugin").unwrap();
+    ///
+    /// let plugin = manager.get_plugin("example_plugin").unwrap();
+    /// assert!(plugin.is_enabled());
+    /// ```
+    pub fn enable_plugin(&mut self, id: PluginID) -> PluginResult<()> {
+        self.check_unlocked()?;
+        let _span = info_span!("enable_plugin", plugin_id = id).entered();
+        match self.plugins.get_mut(id) {
+            Some(plugin) => {
+                plugin.enable();
+                self.bump_generation();
+                Ok(())
+            }
+            None => Err(error::PluginError::NotFound(id.into())),
+        }
+    }
+
+    /// Disables a plugin by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError::NotFound`] if no plugin with the given ID is loaded, and a
+    /// [`PluginError::Locked`] if the manager is [locked](Self::lock).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     ExamplePlugin,
+    ///     "example_plugin",
+    ///     "An example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    /// manager.enable_plugin("example_plugin").unwrap();
+    /// manager.disable_plugin("example_plugin").unwrap();
+    ///
+    /// let plugin = manager.get_plugin("example_plugin").unwrap();
+    /// assert!(!plugin.is_enabled());
+    /// ```
+    pub fn disable_plugin(&mut self, id: PluginID) -> PluginResult<()> {
+        self.check_unlocked()?;
+        let _span = info_span!("disable_plugin", plugin_id = id).entered();
+        match self.plugins.get_mut(id) {
+            Some(plugin) => {
+                plugin.disable();
+                self.bump_generation();
+                Ok(())
+            }
+            None => Err(error::PluginError::NotFound(id.into())),
+        }
+    }
+
+    /// Disables a plugin and all plugins that depend on it.
+    ///
+    /// Plugins declare what they depend on with [`Plugin::dependencies`]. This follows these
+    /// dependencies in reverse, so that plugins depending on a plugin that depends on `id` are
+    /// disabled as well. Unlike [`disable_plugin`](Self::disable_plugin), this keeps the set of
+    /// enabled plugins consistent with their dependencies.
+    ///
+    /// # Returns
+    ///
+    /// The IDs of all disabled plugins, starting with `id`, followed by its dependents in
+    /// breadth-first order. Plugins that were already disabled are included too. If no plugin
+    /// with that ID is loaded, nothing is disabled and the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{Plugin, PluginID, PluginManager, error::PluginResult, hook::HookRegistry};
+    ///
+    /// #[derive(Debug)]
+    /// struct DependentPlugin {
+    ///     id: PluginID,
+    ///     dependencies: Vec<PluginID>,
+    ///     enabled: bool,
+    /// }
+    ///
+    /// impl Plugin for DependentPlugin {
+    ///     fn id(&self) -> PluginID {
+    ///         self.id
+    ///     }
+    ///     fn description(&self) -> &str {
+    ///         "A plugin with dependencies"
+    ///     }
+    ///     fn is_enabled(&self) -> bool {
+    ///         self.enabled
+    ///     }
+    ///     fn enable(&mut self) {
+    ///         self.enabled = true;
+    ///     }
+    ///     fn disable(&mut self) {
+    ///         self.enabled = false;
+    ///     }
+    ///     fn register_hooks(&self, _registry: &mut HookRegistry) -> PluginResult<()> {
+    ///         Ok(())
+    ///     }
+    ///     fn dependencies(&self) -> &[PluginID] {
+    ///         &self.dependencies
+    ///     }
+    /// }
+    ///
+    /// let mut manager = PluginManager::new();
+    /// for (id, dependencies) in [
+    ///     ("config", vec![]),
+    ///     ("network", vec!["config"]),
+    ///     ("sync", vec!["network"]),
+    ///     ("themes", vec![]),
+    /// ] {
+    ///     let plugin = DependentPlugin { id, dependencies, enabled: false };
+    ///     manager.load_plugin(Box::new(plugin)).unwrap();
+    ///     manager.enable_plugin(id).unwrap();
+    /// }
+    ///
+    /// let disabled = manager.disable_plugin_cascade("config");
+    /// assert_eq!(disabled, vec!["config", "network", "sync"]);
+    /// assert_eq!(manager.plugin_is_enabled("sync"), Some(false));
+    /// assert_eq!(manager.plugin_is_enabled("themes"), Some(true));
+    /// ```
+    pub fn disable_plugin_cascade(&mut self, id: PluginID) -> Vec<PluginID> {
+        if self.locked {
+            warn!("Not disabling plugin {id} and its dependents, the plugin manager is locked");
+            return Vec::new();
+        }
+        if !self.plugins.contains_key(id) {
+            return Vec::new();
+        }
+
+        let mut disabled = vec![id];
+        let mut next = 0;
+        while let Some(&current) = disabled.get(next) {
+            next += 1;
+            let mut dependents: Vec<PluginID> = self
+                .plugins
+                .iter()
+                .filter(|(_id, p)| p.dependencies().contains(&current))
+                .map(|(id, _p)| *id)
+                .filter(|dependent| !disabled.contains(dependent))
+                .collect();
+            dependents.sort_unstable();
+            disabled.extend(dependents);
+        }
+
+        for plugin in &disabled {
+            if let Some(plugin) = self.plugins.get_mut(plugin) {
+                plugin.disable();
+            }
+        }
+        self.bump_generation();
+        disabled
+    }
+
+    /// Enables or disables many plugins at once.
+    ///
+    /// Each `(id, enabled)` pair is applied in order with [`enable_plugin`](Self::enable_plugin)
+    /// or [`disable_plugin`](Self::disable_plugin). This is useful for restoring the enabled
+    /// state of plugins from a saved configuration, which may mention plugins that are no longer
+    /// loaded.
+    ///
+    /// Failing for one plugin does not stop the others from being applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BulkError`] with every plugin that could not be enabled or disabled, in the
+    /// same order as `states`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, error::PluginError, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     PluginA,
+    ///     "plugin_a",
+    ///     "The first plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// simple_plugin!(
+    ///     PluginB,
+    ///     "plugin_b",
+    ///     "The second plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(PluginA::new())).unwrap();
+    /// manager.load_plugin(Box::new(PluginB::new())).unwrap();
+    /// manager.enable_plugin("plugin_b").unwrap();
+    ///
+    /// let err = manager
+    ///     .set_enabled_many(&[
+    ///         ("plugin_a", true),
+    ///         ("plugin_b", false),
+    ///         ("removed_plugin", true),
+    ///     ])
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(err.len(), 1);
+    /// assert_eq!(err.errors()[0].0.id(), "removed_plugin");
+    /// assert!(matches!(err.errors()[0].1, PluginError::NotFound(_)));
+    ///
+    /// assert_eq!(manager.plugin_is_enabled("plugin_a"), Some(true));
+    /// assert_eq!(manager.plugin_is_enabled("plugin_b"), Some(false));
+    /// ```
+    pub fn set_enabled_many(&mut self, states: &[(PluginID, bool)]) -> Result<(), BulkError> {
+        BulkError::from_results(self.set_enabled_each(states))
+    }
+
+    /// Internal helper that enables or disables each plugin, and returns the result for each.
+    fn set_enabled_each(
+        &mut self,
+        states: &[(PluginID, bool)],
+    ) -> Vec<(PluginID, PluginResult<()>)> {
+        states
+            .iter()
+            .map(|&(id, enabled)| {
+                let result = if enabled {
+                    self.enable_plugin(id)
+                } else {
+                    self.disable_plugin(id)
+                };
+                (id, result)
+            })
+            .collect()
+    }
+
+    /// Copies the enabled state of all plugins from another manager.
+    ///
+    /// For every plugin that is loaded in both managers, the plugin in `self` is enabled or
+    /// disabled to match the plugin with the same [`PluginID`] in `other`. Plugins that are only
+    /// loaded in one of the managers are left alone. This keeps two managers with the same
+    /// plugins in sync, for example when running a shadow instance next to the real one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     PluginA,
+    ///     "plugin_a",
+    ///     "The first plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// simple_plugin!(
+    ///     PluginB,
+    ///     "plugin_b",
+    ///     "The second plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut prod = PluginManager::new();
+    /// prod.load_plugin(Box::new(PluginA::new())).unwrap();
+    /// prod.load_plugin(Box::new(PluginB::new())).unwrap();
+    /// prod.enable_plugin("plugin_a").unwrap();
+    ///
+    /// let mut shadow = PluginManager::new();
+    /// shadow.load_plugin(Box::new(PluginA::new())).unwrap();
+    /// shadow.copy_enabled_state_from(&prod);
+    ///
+    /// assert_eq!(shadow.plugin_is_enabled("plugin_a"), Some(true));
+    /// assert_eq!(shadow.plugin_is_enabled("plugin_b"), None);
+    /// ```
+    pub fn copy_enabled_state_from(&mut self, other: &PluginManager) {
+        if self.locked {
+            warn!("Not copying the enabled state of plugins, the plugin manager is locked");
+            return;
+        }
+        let mut changed = false;
+        for (id, plugin) in &mut self.plugins {
+            let Some(enabled) = other.plugin_is_enabled(id) else {
+                continue;
+            };
+            if plugin.is_enabled() == enabled {
+                continue;
+            }
+            if enabled {
+                plugin.enable();
+            } else {
+                plugin.disable();
+            }
+            changed = true;
+        }
+        if changed {
+            self.bump_generation();
+        }
+    }
+
+    /// Returns which plugins are loaded, and whether they are enabled.
+    ///
+    /// See [`PluginManagerState`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     PluginA,
+    ///     "plugin_a",
+    ///     "The first plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// simple_plugin!(
+    ///     PluginB,
+    ///     "plugin_b",
+    ///     "The second plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(PluginB::new())).unwrap();
+    /// manager.load_plugin(Box::new(PluginA::new())).unwrap();
+    /// manager.enable_plugin("plugin_b").unwrap();
+    ///
+    /// let state = manager.state();
+    /// assert_eq!(
+    ///     state.plugins,
+    ///     [("plugin_a".into(), false), ("plugin_b".into(), true)]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn state(&self) -> PluginManagerState {
+        let mut plugins: Vec<(PluginIDOwned, bool)> = self
+            .plugins
+            .iter()
+            .map(|(id, plugin)| ((*id).into(), plugin.is_enabled()))
+            .collect();
+        plugins.sort();
+        PluginManagerState { plugins }
+    }
+
+    /// Enables and disables the loaded plugins to match a [`PluginManagerState`].
+    ///
+    /// Plugins in the state that are not loaded are skipped, and loaded plugins that are not in
+    /// the state are left alone. Nothing is loaded or unloaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError::Locked`] if the manager is [locked](Self::lock).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     PluginA,
+    ///     "plugin_a",
+    ///     "The first plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(PluginA::new())).unwrap();
+    /// manager.enable_plugin("plugin_a").unwrap();
+    /// let saved = manager.state();
+    ///
+    /// // on the next run
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(PluginA::new())).unwrap();
+    /// manager.apply_enabled_state(&saved).unwrap();
+    ///
+    /// assert_eq!(manager.plugin_is_enabled("plugin_a"), Some(true));
+    /// ```
+    pub fn apply_enabled_state(&mut self, state: &PluginManagerState) -> PluginResult<()> {
+        self.check_unlocked()?;
+        for (id, enabled) in &state.plugins {
+            let id = id.id();
+            if !self.is_loaded(id) {
+                continue;
+            }
+            if *enabled {
+                self.enable_plugin(id)?;
+            } else {
+                self.disable_plugin(id)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Overrides the [priority](Plugin::priority) of a plugin.
+    ///
+    /// The override replaces the priority reported by the plugin itself, until it is removed with
+    /// [`reset_plugin_priority`](Self::reset_plugin_priority). It is kept when the plugin is
+    /// unloaded, and applies again if a plugin with the same ID is loaded later.
+    ///
+    /// The priority is only read by [`plugin_priority`](Self::plugin_priority) and
+    /// [`get_enabled_hooks_by_ep_prioritized`](Self::get_enabled_hooks_by_ep_prioritized). It does
+    /// not change the order of [`get_enabled_hooks_by_ep`](Self::get_enabled_hooks_by_ep) or of
+    /// the dispatch helpers built on it, like [`publish`](Self::publish) and
+    /// [`resolve_first`](Self::resolve_first), which follow the
+    /// [`SortStrategy`](hook::SortStrategy) of the [`HookRegistry`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError::NotFound`] if no plugin with the given ID is loaded, and a
+    /// [`PluginError::Locked`] if the manager is [locked](Self::lock).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     ExamplePlugin,
+    ///     "example_plugin",
+    ///     "An example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    /// assert_eq!(manager.plugin_priority(ExamplePlugin::ID), Some(0));
+    ///
+    /// manager.set_plugin_priority(ExamplePlugin::ID, 10).unwrap();
+    /// assert_eq!(manager.plugin_priority(ExamplePlugin::ID), Some(10));
+    ///
+    /// manager.reset_plugin_priority(ExamplePlugin::ID);
+    /// assert_eq!(manager.plugin_priority(ExamplePlugin::ID), Some(0));
+    /// ```
+    pub fn set_plugin_priority(&mut self, id: PluginID, priority: i32) -> PluginResult<()> {
+        self.check_unlocked()?;
+        if !self.plugins.contains_key(id) {
+            return Err(PluginError::NotFound(id.into()));
+        }
+        self.priority_overrides.insert(id, priority);
+        self.bump_generation();
+        Ok(())
+    }
+
+    /// Removes a priority override set with [`set_plugin_priority`](Self::set_plugin_priority),
+    /// so that the [priority](Plugin::priority) reported by the plugin applies again.
+    ///
+    /// Does nothing if there is no override for the plugin, or if the manager is
+    /// [locked](Self::lock).
+    pub fn reset_plugin_priority(&mut self, id: PluginID) {
+        if self.locked {
+            warn!("Not resetting the priority of plugin {id}, the plugin manager is locked");
+            return;
+        }
+        if self.priority_overrides.remove(id).is_some() {
+            self.bump_generation();
+        }
+    }
+
+    /// Returns the priority of a plugin, taking overrides from
+    /// [`set_plugin_priority`](Self::set_plugin_priority) into account.
+    ///
+    /// Returns [`None`] if no plugin with the given ID is loaded.
+    #[must_use]
+    pub fn plugin_priority(&self, id: PluginID) -> Option<i32> {
+        let plugin = self.plugins.get(id)?;
+        Some(
+            self.priority_overrides
+                .get(id)
+                .copied()
+                .unwrap_or_else(|| plugin.priority()),
+        )
+    }
+
+    /// Enables or disables a feature of a plugin.
+    ///
+    /// Features let one plugin offer optional parts that the host enables selectively. They are
+    /// passed to the plugin through the [`PluginContext`], and the plugin decides what to do with
+    /// them, typically to register some hooks only if a feature is enabled, see
+    /// [`Plugin::register_hooks_ctx`].
+    ///
+    /// The plugin does not need to be loaded, and features are kept when it is unloaded. Since the
+    /// plugin only sees its features while its hooks are registered or it is loaded, changes
+    /// apply the next time that happens. For a loaded plugin, use
+    /// [`reload_plugin`](Self::reload_plugin) or [`rebuild_registry`](Self::rebuild_registry).
+    ///
+    /// Does nothing if the manager is [locked](Self::lock).
+    ///
+    /// # Examples
+    ///
+    /// See [`Plugin::register_hooks_ctx`].
+    pub fn set_plugin_feature(&mut self, id: PluginID, feature: &str, on: bool) {
+        if self.locked {
+            warn!("Not changing feature {feature} of plugin {id}, the plugin manager is locked");
+            return;
+        }
+        if on {
+            self.plugin_features
+                .entry(id)
+                .or_default()
+                .insert(feature.to_string());
+        } else if let Some(features) = self.plugin_features.get_mut(id) {
+            features.remove(feature);
+            if features.is_empty() {
+                self.plugin_features.remove(id);
+            }
+        }
+    }
+
+    /// Checks if a feature of a plugin was enabled with
+    /// [`set_plugin_feature`](Self::set_plugin_feature).
+    #[must_use]
+    pub fn plugin_feature_enabled(&self, id: PluginID, feature: &str) -> bool {
+        self.plugin_features
+            .get(id)
+            .is_some_and(|features| features.contains(feature))
+    }
+
+    /// Defines a group of plugins that can be enabled and disabled together.
+    ///
+    /// Groups are only metadata of the manager. The members do not need to be loaded when the
+    /// group is defined, and loading or unloading plugins does not change any group. Defining a
+    /// group with a name that is already in use replaces the old group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     DarkColors,
+    ///     "dark_colors",
+    ///     "Dark color scheme",
+    ///     hooks: []
+    /// );
+    ///
+    /// simple_plugin!(
+    ///     DarkIcons,
+    ///     "dark_icons",
+    ///     "Icons for dark color schemes",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(DarkColors::new())).unwrap();
+    /// manager.load_plugin(Box::new(DarkIcons::new())).unwrap();
+    /// manager.define_group("dark_theme", &["dark_colors", "dark_icons"]);
+    ///
+    /// manager.enable_group("dark_theme").unwrap().unwrap();
+    /// assert_eq!(manager.enabled_plugins().len(), 2);
+    ///
+    /// manager.disable_group("dark_theme").unwrap().unwrap();
+    /// assert!(manager.enabled_plugins().is_empty());
+    /// ```
+    pub fn define_group(&mut self, name: &str, members: &[PluginID]) {
+        self.groups.insert(name.to_owned(), members.to_vec());
+    }
+
+    /// Enables all members of a group, see [`define_group`](Self::define_group).
+    ///
+    /// A member that can not be enabled, for example because it is not loaded, does not stop the
+    /// other members from being enabled.
+    ///
+    /// # Returns
+    ///
+    /// A [`BulkError`] with every member that could not be enabled, in the order the members were
+    /// defined, or `Ok(())` if all were enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError::GroupNotFound`] if no group with that name was defined. Failures
+    /// of single members are not errors of the group, they are returned inside of [`Ok`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, error::PluginError, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     DarkColors,
+    ///     "dark_colors",
+    ///     "Dark color scheme",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(DarkColors::new())).unwrap();
+    /// manager.define_group("dark_theme", &["dark_colors", "dark_icons"]);
+    ///
+    /// let err = manager.enable_group("dark_theme").unwrap().unwrap_err();
+    /// assert_eq!(err.len(), 1);
+    /// assert_eq!(err.errors()[0].0.id(), "dark_icons");
+    /// assert!(matches!(err.errors()[0].1, PluginError::NotFound(_)));
+    /// assert_eq!(manager.plugin_is_enabled("dark_colors"), Some(true));
+    ///
+    /// assert!(manager.enable_group("light_theme").is_err());
+    /// ```
+    pub fn enable_group(&mut self, name: &str) -> PluginResult<Result<(), BulkError>> {
+        self.set_group_enabled(name, true)
+    }
+
+    /// Disables all members of a group, see [`define_group`](Self::define_group).
+    ///
+    /// A member that can not be disabled, for example because it is not loaded, does not stop
+    /// the other members from being disabled.
+    ///
+    /// # Returns
+    ///
+    /// A [`BulkError`] with every member that could not be disabled, in the order the members
+    /// were defined, or `Ok(())` if all were disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError::GroupNotFound`] if no group with that name was defined. Failures
+    /// of single members are not errors of the group, they are returned inside of [`Ok`].
+    ///
+    /// # Examples
+    ///
+    /// See [`define_group`](Self::define_group).
+    pub fn disable_group(&mut self, name: &str) -> PluginResult<Result<(), BulkError>> {
+        self.set_group_enabled(name, false)
+    }
+
+    /// Internal helper for [`enable_group`](Self::enable_group) and
+    /// [`disable_group`](Self::disable_group).
+    fn set_group_enabled(
+        &mut self,
+        name: &str,
+        enabled: bool,
+    ) -> PluginResult<Result<(), BulkError>> {
+        let states: Vec<(PluginID, bool)> = self
+            .groups
+            .get(name)
+            .ok_or_else(|| PluginError::GroupNotFound(name.to_owned()))?
+            .iter()
+            .map(|&id| (id, enabled))
+            .collect();
+        Ok(BulkError::from_results(self.set_enabled_each(&states)))
+    }
+
+    /// Gets all hooks of enabled [Plugins](Plugin) for a specific [`ExtensionPoint`] type.
+    ///
+    /// This method filters hooks by both extension point type and plugin enabled status,
+    /// returning only hooks from enabled plugins.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The [`ExtensionPoint`] type
+    ///
+    /// # Returns
+    ///
+    /// A vector of tuples containing references to [`HookID`](crate::hook::HookID)s and hooks registered for the [`ExtensionPoint`]
+    /// from enabled plugins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     Logger: LoggerTrait;
+    ///     fn log(&self, message: &str);
+    /// );
+    ///
+    /// struct ConsoleLogger;
+    /// impl LoggerTrait for ConsoleLogger {
+    ///     fn log(&self, message: &str) {
+    ///         // In a real implementation, this would print to console
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     LoggerPlugin,
+    ///     "logger_plugin",
+    ///     "Basic logging plugin",
+    ///     hooks: [(Logger, ConsoleLogger)]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(LoggerPlugin::new())).unwrap();
+    /// manager.enable_plugin(LoggerPlugin::ID).unwrap();
+    ///
+    /// // Get all enabled hooks for the Logger extension point
+    /// let hooks = manager.get_enabled_hooks_by_ep::<Logger>();
+    /// assert_eq!(hooks.len(), 1);
+    ///
+    /// // Use the hook
+    /// for (id, hook) in hooks {
+    ///     assert_eq!(id.plugin_id, "logger_plugin");
+    ///     hook.inner().log("Hello from logger!");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn get_enabled_hooks_by_ep<E: ExtensionPoint>(
+        &self,
+    ) -> Vec<(&hook::HookID, &hook::Hook<E>)> {
+        self.hook_registry()
+            .get_by_extension_point()
+            .into_iter()
+            .filter(|(id, _hook)| self.hook_is_active(id))
+            .collect()
+    }
+
+    /// Gets the hooks for a specific [`ExtensionPoint`] type, like
+    /// [`get_enabled_hooks_by_ep`](Self::get_enabled_hooks_by_ep), but only from plugins that
+    /// carry a specific [tag](Plugin::tags).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, register_hook, Plugin, PluginID, PluginManager, error::PluginResult, hook::HookRegistry};
+    ///
+    /// extension_point!(
+    ///     PaymentProcessor: PaymentProcessorTrait;
+    ///     fn charge(&self, cents: u64) -> bool;
+    /// );
+    ///
+    /// struct AlwaysApprove;
+    /// impl PaymentProcessorTrait for AlwaysApprove {
+    ///     fn charge(&self, _cents: u64) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug)]
+    /// struct PaymentPlugin {
+    ///     id: PluginID,
+    ///     tags: Vec<&'static str>,
+    /// }
+    ///
+    /// impl Plugin for PaymentPlugin {
+    ///     fn id(&self) -> PluginID {
+    ///         self.id
+    ///     }
+    ///     fn description(&self) -> &str {
+    ///         "Processes payments"
+    ///     }
+    ///     fn is_enabled(&self) -> bool {
+    ///         true
+    ///     }
+    ///     fn enable(&mut self) {}
+    ///     fn disable(&mut self) {}
+    ///     fn register_hooks(&self, registry: &mut HookRegistry) -> PluginResult<()> {
+    ///         register_hook!(registry, self.id, PaymentProcessor, AlwaysApprove);
+    ///         Ok(())
+    ///     }
+    ///     fn tags(&self) -> &[&str] {
+    ///         &self.tags
+    ///     }
+    /// }
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(PaymentPlugin { id: "bank", tags: vec!["trusted"] })).unwrap();
+    /// manager.load_plugin(Box::new(PaymentPlugin { id: "sketchy", tags: vec![] })).unwrap();
+    ///
+    /// let hooks = manager.get_enabled_hooks_by_ep_tagged::<PaymentProcessor>("trusted");
+    /// assert_eq!(hooks.len(), 1);
+    /// assert_eq!(hooks[0].0.plugin_id, "bank");
+    /// ```
+    #[must_use]
+    pub fn get_enabled_hooks_by_ep_tagged<E: ExtensionPoint>(
+        &self,
+        tag: &str,
+    ) -> Vec<(&hook::HookID, &hook::Hook<E>)> {
+        self.hook_registry()
+            .get_by_extension_point()
+            .into_iter()
+            .filter(|(id, _hook)| {
+                self.plugins
+                    .get(id.plugin_id)
+                    .is_some_and(|plugin| plugin.tags().contains(&tag))
+                    && self.hook_is_active(id)
+            })
+            .collect()
+    }
+
+    /// Gets the hooks of enabled [Plugins](Plugin) for a specific [`ExtensionPoint`] type, with
+    /// the hooks of plugins with a higher [priority](Self::plugin_priority) first.
+    ///
+    /// Hooks of plugins with the same priority keep the order of
+    /// [`get_enabled_hooks_by_ep`](Self::get_enabled_hooks_by_ep).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     Theme: ThemeTrait;
+    ///     fn color(&self) -> &'static str;
+    /// );
+    ///
+    /// struct Dark;
+    /// impl ThemeTrait for Dark {
+    ///     fn color(&self) -> &'static str {
+    ///         "black"
+    ///     }
+    /// }
+    ///
+    /// struct Light;
+    /// impl ThemeTrait for Light {
+    ///     fn color(&self) -> &'static str {
+    ///         "white"
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(DarkPlugin, "a_dark", "Dark theme", hooks: [(Theme, Dark)]);
+    /// simple_plugin!(LightPlugin, "b_light", "Light theme", hooks: [(Theme, Light)]);
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(DarkPlugin::new())).unwrap();
+    /// manager.load_plugin(Box::new(LightPlugin::new())).unwrap();
+    /// manager.enable_plugin(DarkPlugin::ID).unwrap();
+    /// manager.enable_plugin(LightPlugin::ID).unwrap();
+    ///
+    /// let first = |manager: &PluginManager| {
+    ///     manager.get_enabled_hooks_by_ep_prioritized::<Theme>()[0].1.inner().color()
+    /// };
+    /// assert_eq!(first(&manager), "black");
+    ///
+    /// // bring the light theme to the front
+    /// manager.set_plugin_priority(LightPlugin::ID, 1).unwrap();
+    /// assert_eq!(first(&manager), "white");
+    /// ```
+    #[must_use]
+    pub fn get_enabled_hooks_by_ep_prioritized<E: ExtensionPoint>(
+        &self,
+    ) -> Vec<(&hook::HookID, &hook::Hook<E>)> {
+        let mut hooks = self.get_enabled_hooks_by_ep::<E>();
+        hooks.sort_by_cached_key(|(id, _hook)| {
+            std::cmp::Reverse(self.plugin_priority(id.plugin_id).unwrap_or_default())
+        });
+        hooks
+    }
+
+    /// Gets any hook of an enabled [`Plugin`] for a specific [`ExtensionPoint`] type.
+    ///
+    /// Like [`HookRegistry::get_any`], but only considers hooks of enabled plugins. The returned
+    /// hook is the first in the order of [`get_enabled_hooks_by_ep`](Self::get_enabled_hooks_by_ep).
+    /// If no enabled plugin provides a hook, this falls back to the
+    /// [default hook](ExtensionPoint::default_hook) of the extension point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     Clock: ClockTrait;
+    ///     fn now(&self) -> u64;
+    /// );
+    ///
+    /// struct FixedClock;
+    /// impl ClockTrait for FixedClock {
+    ///     fn now(&self) -> u64 {
+    ///         1337
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     ClockPlugin,
+    ///     "clock_plugin",
+    ///     "Provides the time",
+    ///     hooks: [(Clock, FixedClock)]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ClockPlugin::new())).unwrap();
+    /// assert!(manager.get_any_enabled::<Clock>().is_none());
+    ///
+    /// manager.enable_plugin(ClockPlugin::ID).unwrap();
+    /// let clock = manager.get_any_enabled::<Clock>().unwrap();
+    /// assert_eq!(clock.inner().now(), 1337);
+    /// ```
+    #[must_use]
+    pub fn get_any_enabled<E: ExtensionPoint>(&self) -> Option<hook::HookOrDefault<'_, E>> {
+        self.get_enabled_hooks_by_ep::<E>()
+            .into_iter()
+            .next()
+            .map(|(_id, hook)| hook::HookOrDefault::Registered(hook))
+            .or_else(|| E::default_hook().map(hook::HookOrDefault::Default))
+    }
+
+    /// Gets the [`HookID`](hook::HookID)s of all hooks of enabled [Plugins](Plugin) for a specific
+    /// [`ExtensionPoint`] type.
+    ///
+    /// The returned ids are owned and in the same order as the hooks returned by
+    /// [`get_enabled_hooks_by_ep`](Self::get_enabled_hooks_by_ep), which makes them suitable for
+    /// caching together with the [`generation`](Self::generation) of the plugin manager.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     Logger: LoggerTrait;
+    ///     fn log(&self, message: &str);
+    /// );
+    ///
+    /// struct ConsoleLogger;
+    /// impl LoggerTrait for ConsoleLogger {
+    ///     fn log(&self, message: &str) {}
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     LoggerPlugin,
+    ///     "logger_plugin",
+    ///     "Basic logging plugin",
+    ///     hooks: [(Logger, ConsoleLogger)]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(LoggerPlugin::new())).unwrap();
+    /// assert!(manager.enabled_hook_ids::<Logger>().is_empty());
+    ///
+    /// manager.enable_plugin(LoggerPlugin::ID).unwrap();
+    /// let cached = (manager.generation(), manager.enabled_hook_ids::<Logger>());
+    /// assert_eq!(cached.1.len(), 1);
+    /// assert_eq!(cached.1[0].plugin_id, "logger_plugin");
+    /// ```
+    #[must_use]
+    pub fn enabled_hook_ids<E: ExtensionPoint>(&self) -> Vec<hook::HookID> {
+        self.get_enabled_hooks_by_ep::<E>()
+            .into_iter()
+            .map(|(id, _hook)| id.clone())
+            .collect()
+    }
+
+    /// Calls a single hook of an enabled [`Plugin`] by [`HookID`](hook::HookID).
+    ///
+    /// This looks up the hook for the [`ExtensionPoint`] `E`, and calls `f` with its
+    /// implementation if the plugin owning it is enabled.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(R)` with the return value of `f`, if the hook was called
+    /// - `None` if no such hook exists for `E`, or it or its plugin is not enabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager, hook::HookID};
+    ///
+    /// extension_point!(
+    ///     Adder: AdderTrait;
+    ///     fn add(&self, a: i32, b: i32) -> i32;
+    /// );
+    ///
+    /// struct SimpleAdder;
+    /// impl AdderTrait for SimpleAdder {
+    ///     fn add(&self, a: i32, b: i32) -> i32 {
+    ///         a + b
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     MathPlugin,
+    ///     "math_plugin",
+    ///     "Does math",
+    ///     hooks: [(Adder, SimpleAdder)]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(MathPlugin::new())).unwrap();
+    ///
+    /// let id = HookID::for_ep::<Adder>(MathPlugin::ID, None);
+    /// assert_eq!(manager.invoke_hook::<Adder, _>(&id, |adder| adder.add(2, 3)), None);
+    ///
+    /// manager.enable_plugin(MathPlugin::ID).unwrap();
+    /// assert_eq!(manager.invoke_hook::<Adder, _>(&id, |adder| adder.add(2, 3)), Some(5));
+    /// ```
+    pub fn invoke_hook<E: ExtensionPoint, R>(
+        &self,
+        id: &hook::HookID,
+        f: impl FnOnce(&E::HookTrait) -> R,
+    ) -> Option<R> {
+        if !self.hook_is_active(id) {
+            return None;
+        }
+        let hook = self.hook_registry.get::<E>(id)?;
+        Some(f(hook.inner()))
+    }
+
+    /// Lists which [`Plugin`] provides hooks for which [`ExtensionPoint`].
+    ///
+    /// Each entry is an edge between a plugin and an extension point it provides at least one hook
+    /// for, given as the [`PluginID`], the [`ExtensionPointID`](hook::ExtensionPointID) and the
+    /// [name](ExtensionPoint::name) of the extension point. A host can render this as a bipartite
+    /// graph of plugins and extension points. Every edge is listed once, regardless of how many
+    /// hooks the plugin registered for the extension point, and enabled and disabled plugins are
+    /// included alike.
+    ///
+    /// The edges are sorted by [`PluginID`], then by extension point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager, hook::ExtensionPoint};
+    ///
+    /// extension_point!(
+    ///     Logger: LoggerTrait;
+    ///     fn log(&self, message: &str);
+    /// );
+    ///
+    /// struct ConsoleLogger;
+    /// impl LoggerTrait for ConsoleLogger {
+    ///     fn log(&self, message: &str) {}
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     LoggerPlugin,
+    ///     "logger_plugin",
+    ///     "Logs to the console, twice",
+    ///     hooks: [(Logger, ConsoleLogger, "first"), (Logger, ConsoleLogger, "second")]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(LoggerPlugin::new())).unwrap();
+    ///
+    /// assert_eq!(
+    ///     manager.provision_graph(),
+    ///     vec![("logger_plugin", Logger::id(), Logger::name())]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn provision_graph(&self) -> Vec<(PluginID, hook::ExtensionPointID, &'static str)> {
+        let mut edges: Vec<_> = self
+            .hook_registry
+            .get_by_filter(|(id, _hook)| self.plugins.contains_key(id.plugin_id))
+            .into_iter()
+            .map(|(id, hook)| (id.plugin_id, hook.eid(), hook.ep_name()))
+            .collect();
+        // the hooks are sorted by id, so duplicates are next to each other
+        edges.dedup();
+        edges
+    }
+
+    /// Lists the [extension points](ExtensionPoint) a [`Plugin`] has no hook for.
+    ///
+    /// The extension points are taken from the [`HookRegistry`]: an extension point is known if
+    /// any plugin registered a hook for it. This lets plugin authors check that a plugin provides
+    /// all extension points of some contract, by loading it next to a reference plugin that
+    /// provides all of them.
+    ///
+    /// Each entry is the [`ExtensionPointID`](hook::ExtensionPointID) and the
+    /// [name](ExtensionPoint::name) of a missing extension point, sorted by name. If the plugin is
+    /// not loaded, the result is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager, hook::ExtensionPoint};
+    ///
+    /// extension_point!(
+    ///     Reader: ReaderTrait;
+    /// );
+    /// extension_point!(
+    ///     Writer: WriterTrait;
+    /// );
+    ///
+    /// struct Noop;
+    /// impl ReaderTrait for Noop {}
+    /// impl WriterTrait for Noop {}
+    ///
+    /// simple_plugin!(
+    ///     FullPlugin,
+    ///     "full_plugin",
+    ///     "Reads and writes",
+    ///     hooks: [(Reader, Noop), (Writer, Noop)]
+    /// );
+    /// simple_plugin!(
+    ///     ReadOnlyPlugin,
+    ///     "read_only_plugin",
+    ///     "Only reads",
+    ///     hooks: [(Reader, Noop)]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(FullPlugin::new())).unwrap();
+    /// manager.load_plugin(Box::new(ReadOnlyPlugin::new())).unwrap();
+    ///
+    /// assert!(manager.missing_extension_points_for(FullPlugin::ID).is_empty());
+    /// assert_eq!(
+    ///     manager.missing_extension_points_for(ReadOnlyPlugin::ID),
+    ///     vec![(Writer::id(), Writer::name())]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn missing_extension_points_for(
+        &self,
+        plugin_id: PluginID,
+    ) -> Vec<(hook::ExtensionPointID, &'static str)> {
+        if !self.plugins.contains_key(plugin_id) {
+            return Vec::new();
+        }
+
+        let all_hooks = self.hook_registry.get_by_filter(|_| true);
+        let provided: std::collections::HashSet<hook::ExtensionPointID> = all_hooks
+            .iter()
+            .filter(|(id, _hook)| id.plugin_id == plugin_id)
+            .map(|(_id, hook)| hook.eid())
+            .collect();
+        let mut missing: Vec<_> = all_hooks
+            .iter()
+            .filter(|(_id, hook)| !provided.contains(&hook.eid()))
+            .map(|(_id, hook)| (hook.eid(), hook.ep_name()))
+            .collect();
+        missing.sort_unstable_by_key(|(eid, name)| (*name, *eid));
+        missing.dedup();
+        missing
+    }
+
+    /// Checks if a hook with the given [`HookID`](hook::HookID) is registered.
+    ///
+    /// This is a shorthand for [`HookRegistry::exists`] on the [`hook_registry`](Self::hook_registry),
+    /// and does not care whether the owning plugin is enabled. See
+    /// [`enabled_contains_hook`](Self::enabled_contains_hook) for that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager, hook::HookID};
+    ///
+    /// extension_point!(
+    ///     Logger: LoggerTrait;
+    ///     fn log(&self, message: &str);
+    /// );
+    ///
+    /// struct ConsoleLogger;
+    /// impl LoggerTrait for ConsoleLogger {
+    ///     fn log(&self, message: &str) {}
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     LoggerPlugin,
+    ///     "logger_plugin",
+    ///     "Basic logging plugin",
+    ///     hooks: [(Logger, ConsoleLogger)]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(LoggerPlugin::new())).unwrap();
+    ///
+    /// let id = HookID::for_ep::<Logger>(LoggerPlugin::ID, None);
+    /// assert!(manager.contains_hook(&id));
+    /// assert!(!manager.enabled_contains_hook(&id));
+    ///
+    /// manager.enable_plugin(LoggerPlugin::ID).unwrap();
+    /// assert!(manager.enabled_contains_hook(&id));
+    /// ```
+    #[must_use]
+    pub fn contains_hook(&self, id: &hook::HookID) -> bool {
+        self.hook_registry.exists(id)
+    }
+
+    /// Checks if a hook with the given [`HookID`](hook::HookID) is registered and
+    /// [enabled](HookRegistry::set_hook_enabled), and the [`Plugin`] owning it is enabled.
+    ///
+    /// # Examples
+    ///
+    /// See [`contains_hook`](Self::contains_hook).
+    #[must_use]
+    pub fn enabled_contains_hook(&self, id: &hook::HookID) -> bool {
+        self.hook_is_active(id)
+    }
+
+    /// Gets all mutable hooks of enabled [Plugins](Plugin) for a specific [`ExtensionPoint`] type.
+    ///
+    /// This method filters hooks by both extension point type and plugin enabled status,
+    /// returning only hooks from enabled plugins.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The [`ExtensionPoint`] type
+    ///
+    /// # Returns
+    ///
+    /// A vector of tuples containing mutable references to [`HookID`](crate::hook::HookID)s and hooks registered for the [`ExtensionPoint`]
+    /// from enabled plugins. [Shared](hook::Hook#ownership) hooks that are still referenced
+    /// elsewhere are left out, as they can not be accessed mutably.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     Logger: LoggerTrait;
+    ///     fn log(&self, message: &str);
+    /// );
+    ///
+    /// struct ConsoleLogger;
+    /// impl LoggerTrait for ConsoleLogger {
+    ///     fn log(&self, message: &str) {
+    ///         // In a real implementation, this would print to console
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     LoggerPlugin,
+    ///     "logger_plugin",
+    ///     "Basic logging plugin",
+    ///     hooks: [(Logger, ConsoleLogger)]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(LoggerPlugin::new())).unwrap();
+    /// manager.enable_plugin(LoggerPlugin::ID).unwrap();
+    ///
+    /// // Get all enabled hooks for the Logger extension point
+    /// let hooks = manager.get_enabled_hooks_by_ep::<Logger>();
+    /// assert_eq!(hooks.len(), 1);
+    ///
+    /// // Use the hook
+    /// for (id, hook) in hooks {
+    ///     assert_eq!(id.plugin_id, "logger_plugin");
+    ///     hook.inner().log("Hello from logger!");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn get_enabled_hooks_by_ep_mut<E: ExtensionPoint>(
+        &mut self,
+    ) -> Vec<(&hook::HookID, &mut hook::Hook<E>)> {
+        let active_ids: std::collections::HashSet<hook::HookID> = self
+            .hook_registry
+            .get_by_filter(|(id, hook)| hook.eid() == E::id() && self.hook_is_active(id))
+            .into_iter()
+            .map(|(id, _hook)| id.clone())
+            .collect();
+        self.hook_registry
             .get_by_extension_point_mut()
             .into_iter()
-            .filter(|(id, _hook)| enabled_ids.contains(&id.plugin_id))
-            .collect()
+            .filter(|(id, _hook)| active_ids.contains(id))
+            .collect()
+    }
+
+    /// Executes a function with mutable access for each hook of enabled [Plugins](Plugin) for a
+    /// specific [`ExtensionPoint`] type.
+    ///
+    /// The hooks are visited in the same order as returned by
+    /// [`get_enabled_hooks_by_ep_mut`](Self::get_enabled_hooks_by_ep_mut). Changes to the hooks
+    /// stay in the [`HookRegistry`], so stateful hooks can accumulate state over many calls, like
+    /// a metrics collector.
+    ///
+    /// [Shared](hook::Hook#ownership) hooks that are still referenced elsewhere are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     Counter: CounterTrait;
+    ///     fn record(&mut self, amount: u32);
+    ///     fn total(&self) -> u32;
+    /// );
+    ///
+    /// #[derive(Default)]
+    /// struct SumCounter {
+    ///     sum: u32,
+    /// }
+    /// impl CounterTrait for SumCounter {
+    ///     fn record(&mut self, amount: u32) {
+    ///         self.sum += amount;
+    ///     }
+    ///     fn total(&self) -> u32 {
+    ///         self.sum
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     CounterPlugin,
+    ///     "counter_plugin",
+    ///     "Counts things",
+    ///     hooks: [(Counter, SumCounter::default())]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(CounterPlugin::new())).unwrap();
+    /// manager.enable_plugin(CounterPlugin::ID).unwrap();
+    ///
+    /// manager.for_each_hook_mut::<Counter>(|hook| hook.inner_mut().record(2));
+    /// manager.for_each_hook_mut::<Counter>(|hook| hook.inner_mut().record(3));
+    ///
+    /// let (_id, counter) = &manager.get_enabled_hooks_by_ep::<Counter>()[0];
+    /// assert_eq!(counter.inner().total(), 5);
+    /// ```
+    pub fn for_each_hook_mut<E: ExtensionPoint>(&mut self, mut f: impl FnMut(&mut hook::Hook<E>)) {
+        for (_id, hook) in self.get_enabled_hooks_by_ep_mut::<E>() {
+            f(hook);
+        }
+    }
+
+    /// Threads an accumulator through the hooks of enabled [Plugins](Plugin) for a specific
+    /// [`ExtensionPoint`] type.
+    ///
+    /// Starting with `init`, `f` is called for every hook with the current accumulator, and
+    /// returns the next one. The hooks are visited in the same order as returned by
+    /// [`get_enabled_hooks_by_ep`](Self::get_enabled_hooks_by_ep).
+    ///
+    /// # Returns
+    ///
+    /// The accumulator returned for the last hook, or `init` if there are no hooks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     TextFilter: TextFilterTrait;
+    ///     fn filter(&self, text: String) -> String;
+    /// );
+    ///
+    /// struct Trim;
+    /// impl TextFilterTrait for Trim {
+    ///     fn filter(&self, text: String) -> String {
+    ///         text.trim().to_string()
+    ///     }
+    /// }
+    ///
+    /// struct Exclaim;
+    /// impl TextFilterTrait for Exclaim {
+    ///     fn filter(&self, text: String) -> String {
+    ///         format!("{text}!")
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     TextPlugin,
+    ///     "text_plugin",
+    ///     "Filters text",
+    ///     hooks: [(TextFilter, Exclaim, "b_exclaim"), (TextFilter, Trim, "a_trim")]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(TextPlugin::new())).unwrap();
+    /// manager.enable_plugin(TextPlugin::ID).unwrap();
+    ///
+    /// let text = manager.fold_hooks::<TextFilter, _>("  hello ".to_string(), |text, hook| {
+    ///     hook.inner().filter(text)
+    /// });
+    /// assert_eq!(text, "hello!");
+    /// ```
+    pub fn fold_hooks<E: ExtensionPoint, A>(
+        &self,
+        init: A,
+        mut f: impl FnMut(A, &hook::Hook<E>) -> A,
+    ) -> A {
+        self.get_enabled_hooks_by_ep::<E>()
+            .into_iter()
+            .fold(init, |acc, (_id, hook)| f(acc, hook))
+    }
+
+    /// Threads an accumulator through the hooks of enabled [Plugins](Plugin) for a specific
+    /// [`ExtensionPoint`] type, stopping at the first error.
+    ///
+    /// This is like [`fold_hooks`](Self::fold_hooks), for hooks with fallible methods. The
+    /// extension point can simply declare methods that return a [`Result`] with a shared error
+    /// type. As soon as `f` returns an error, the remaining hooks are skipped and the error is
+    /// returned, which makes this a good fit for validation chains.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// pub struct ConfigError(String);
+    ///
+    /// extension_point!(
+    ///     ConfigCheck: ConfigCheckTrait;
+    ///     fn check(&self, value: u32) -> Result<u32, ConfigError>;
+    /// );
+    ///
+    /// struct NotZero;
+    /// impl ConfigCheckTrait for NotZero {
+    ///     fn check(&self, value: u32) -> Result<u32, ConfigError> {
+    ///         if value == 0 {
+    ///             return Err(ConfigError("must not be zero".to_string()));
+    ///         }
+    ///         Ok(value)
+    ///     }
+    /// }
+    ///
+    /// struct Clamp;
+    /// impl ConfigCheckTrait for Clamp {
+    ///     fn check(&self, value: u32) -> Result<u32, ConfigError> {
+    ///         Ok(value.min(64))
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     ConfigPlugin,
+    ///     "config_plugin",
+    ///     "Checks the configuration",
+    ///     hooks: [(ConfigCheck, NotZero, "a_not_zero"), (ConfigCheck, Clamp, "b_clamp")]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ConfigPlugin::new())).unwrap();
+    /// manager.enable_plugin(ConfigPlugin::ID).unwrap();
+    ///
+    /// let check = |value| {
+    ///     manager.try_fold_hooks::<ConfigCheck, _, _, _>(value, |value, hook| {
+    ///         hook.inner().check(value)
+    ///     })
+    /// };
+    /// assert_eq!(check(100), Ok(64));
+    /// assert_eq!(check(0), Err(ConfigError("must not be zero".to_string())));
+    /// ```
+    pub fn try_fold_hooks<E, A, Err, F>(&self, init: A, mut f: F) -> Result<A, Err>
+    where
+        E: ExtensionPoint,
+        F: FnMut(A, &hook::Hook<E>) -> Result<A, Err>,
+    {
+        self.get_enabled_hooks_by_ep::<E>()
+            .into_iter()
+            .try_fold(init, |acc, (_id, hook)| f(acc, hook))
+    }
+
+    /// Threads an accumulator through the hooks of enabled [Plugins](Plugin) for a specific
+    /// [`ExtensionPoint`] type, with mutable access to the hooks.
+    ///
+    /// This is like [`fold_hooks`](Self::fold_hooks), for hooks with `&mut self` methods. The
+    /// hooks are visited in the same order as returned by
+    /// [`get_enabled_hooks_by_ep_mut`](Self::get_enabled_hooks_by_ep_mut).
+    ///
+    /// [Shared](hook::Hook#ownership) hooks that are still referenced elsewhere are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     Tokenizer: TokenizerTrait;
+    ///     fn tokenize(&mut self, text: &str) -> usize;
+    /// );
+    ///
+    /// #[derive(Default)]
+    /// struct WordTokenizer {
+    ///     calls: u32,
+    /// }
+    /// impl TokenizerTrait for WordTokenizer {
+    ///     fn tokenize(&mut self, text: &str) -> usize {
+    ///         self.calls += 1;
+    ///         text.split_whitespace().count()
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     TokenizerPlugin,
+    ///     "tokenizer_plugin",
+    ///     "Counts words",
+    ///     hooks: [(Tokenizer, WordTokenizer::default())]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(TokenizerPlugin::new())).unwrap();
+    /// manager.enable_plugin(TokenizerPlugin::ID).unwrap();
+    ///
+    /// let words = manager.fold_hooks_mut::<Tokenizer, _>(0, |total, hook| {
+    ///     total + hook.inner_mut().tokenize("one two three")
+    /// });
+    /// assert_eq!(words, 3);
+    /// ```
+    pub fn fold_hooks_mut<E: ExtensionPoint, A>(
+        &mut self,
+        init: A,
+        mut f: impl FnMut(A, &mut hook::Hook<E>) -> A,
+    ) -> A {
+        self.get_enabled_hooks_by_ep_mut::<E>()
+            .into_iter()
+            .fold(init, |acc, (_id, hook)| f(acc, hook))
+    }
+
+    /// Executes a function for hooks of enabled [Plugins](Plugin) for a specific
+    /// [`ExtensionPoint`] type, until a time budget is exhausted.
+    ///
+    /// A [`HookBudget`](hook::HookBudget) with the given `limit` is created and passed to `f`
+    /// together with each hook, in the same order as returned by
+    /// [`get_enabled_hooks_by_ep`](Self::get_enabled_hooks_by_ep). Once the budget is exhausted,
+    /// the remaining hooks are skipped.
+    ///
+    /// The budget is only checked *between* hooks. A single hook that runs for too long can not be
+    /// stopped, it can only check the budget itself and cooperate by returning early.
+    ///
+    /// # Returns
+    ///
+    /// The number of hooks that `f` was called for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use steckrs::{extension_point, simple_plugin, PluginManager, hook::HookBudget};
+    ///
+    /// extension_point!(
+    ///     Worker: WorkerTrait;
+    ///     fn work(&self, budget: &HookBudget) -> u32;
+    /// );
+    ///
+    /// struct DiligentWorker;
+    /// impl WorkerTrait for DiligentWorker {
+    ///     fn work(&self, budget: &HookBudget) -> u32 {
+    ///         let mut done = 0;
+    ///         while !budget.is_exhausted() && done < 1000 {
+    ///             done += 1;
+    ///         }
+    ///         done
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     WorkerPlugin,
+    ///     "worker_plugin",
+    ///     "Does work",
+    ///     hooks: [(Worker, DiligentWorker, "a"), (Worker, DiligentWorker, "b")]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(WorkerPlugin::new())).unwrap();
+    /// manager.enable_plugin(WorkerPlugin::ID).unwrap();
+    ///
+    /// let mut total = 0;
+    /// let called = manager.for_each_hook_budgeted::<Worker>(
+    ///     Duration::from_secs(10),
+    ///     |budget, hook| total += hook.inner().work(budget),
+    /// );
+    /// assert_eq!(called, 2);
+    /// assert_eq!(total, 2000);
+    ///
+    /// // no budget, no work
+    /// let called = manager.for_each_hook_budgeted::<Worker>(Duration::ZERO, |_, _| ());
+    /// assert_eq!(called, 0);
+    /// ```
+    pub fn for_each_hook_budgeted<E: ExtensionPoint>(
+        &self,
+        limit: std::time::Duration,
+        mut f: impl FnMut(&hook::HookBudget, &hook::Hook<E>),
+    ) -> usize {
+        let budget = hook::HookBudget::new(limit);
+        let mut called = 0;
+        for (id, hook) in self.get_enabled_hooks_by_ep::<E>() {
+            if budget.is_exhausted() {
+                warn!("Hook budget exhausted, skipping remaining hooks, starting with {id:?}");
+                break;
+            }
+            f(&budget, hook);
+            called += 1;
+        }
+        called
+    }
+
+    /// Calls `f` for every hook of enabled [Plugins](Plugin) for a specific [`ExtensionPoint`]
+    /// type, and counts the calls per hook.
+    ///
+    /// This works like iterating over [`get_enabled_hooks_by_ep`](Self::get_enabled_hooks_by_ep),
+    /// but also increments a counter for the [`HookID`](hook::HookID) of each hook. The counters
+    /// can be read with [`invocation_counts`](Self::invocation_counts), to find out which hooks
+    /// are used often and which are hardly used at all.
+    ///
+    /// Only calls made through this method are counted, the manager can not see what is done with
+    /// hooks that were handed out in other ways.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager, hook::HookID};
+    ///
+    /// extension_point!(
+    ///     Greeter: GreeterTrait;
+    ///     fn greet(&self) -> &'static str;
+    /// );
+    ///
+    /// struct Hello;
+    /// impl GreeterTrait for Hello {
+    ///     fn greet(&self) -> &'static str {
+    ///         "hello"
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     GreeterPlugin,
+    ///     "greeter_plugin",
+    ///     "Greets",
+    ///     hooks: [(Greeter, Hello)]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(GreeterPlugin::new())).unwrap();
+    /// manager.enable_plugin(GreeterPlugin::ID).unwrap();
+    ///
+    /// for _ in 0..3 {
+    ///     manager.for_each_hook_counted::<Greeter>(|hook| assert_eq!(hook.inner().greet(), "hello"));
+    /// }
+    ///
+    /// let id = HookID::for_ep::<Greeter>(GreeterPlugin::ID, None);
+    /// assert_eq!(manager.invocation_counts()[&id], 3);
+    /// ```
+    pub fn for_each_hook_counted<E: ExtensionPoint>(&self, mut f: impl FnMut(&hook::Hook<E>)) {
+        for (id, hook) in self.get_enabled_hooks_by_ep::<E>() {
+            *self.lock_invocation_counts().entry(id.clone()).or_default() += 1;
+            f(hook);
+        }
+    }
+
+    /// Returns how often each hook was called with
+    /// [`for_each_hook_counted`](Self::for_each_hook_counted).
+    ///
+    /// Hooks that were never called this way are not included. The counters are kept when a
+    /// plugin is unloaded, so hooks of plugins that are loaded again keep counting from where they
+    /// left off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::PluginManager;
+    ///
+    /// let manager = PluginManager::new();
+    /// assert!(manager.invocation_counts().is_empty());
+    /// ```
+    #[must_use]
+    pub fn invocation_counts(&self) -> HashMap<hook::HookID, u64> {
+        self.lock_invocation_counts().clone()
+    }
+
+    /// Internal helper that locks the invocation counters, ignoring poisoning.
+    ///
+    /// Each counter is only ever incremented as a whole, so a panic can not leave them in an
+    /// inconsistent state.
+    fn lock_invocation_counts(&self) -> std::sync::MutexGuard<'_, HashMap<hook::HookID, u64>> {
+        self.invocation_counts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Calls `f` for every hook of enabled [Plugins](Plugin) for a specific [`ExtensionPoint`]
+    /// type, in parallel.
+    ///
+    /// The hooks are distributed over the global thread pool of [`rayon`]. This is meant for
+    /// extension points whose hooks do independent, CPU-heavy work. Hooks are always
+    /// [`Send`] and [`Sync`], so they can be called from any thread, but `f` has to be [`Sync`]
+    /// too.
+    ///
+    /// Unlike the other methods for calling hooks, the order in which the hooks are called is not
+    /// defined, and calls may overlap.
+    ///
+    /// This is only available with the `rayon` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     Summer: SummerTrait;
+    ///     fn sum(&self) -> u64;
+    /// );
+    ///
+    /// struct Lower;
+    /// impl SummerTrait for Lower {
+    ///     fn sum(&self) -> u64 {
+    ///         (0..1000).sum()
+    ///     }
+    /// }
+    ///
+    /// struct Upper;
+    /// impl SummerTrait for Upper {
+    ///     fn sum(&self) -> u64 {
+    ///         (1000..2000).sum()
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     SumPlugin,
+    ///     "sum_plugin",
+    ///     "Sums numbers in chunks",
+    ///     hooks: [(Summer, Lower, "lower"), (Summer, Upper, "upper")]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(SumPlugin::new())).unwrap();
+    /// manager.enable_plugin(SumPlugin::ID).unwrap();
+    ///
+    /// let total = AtomicU64::new(0);
+    /// manager.par_for_each_hook::<Summer>(|hook| {
+    ///     total.fetch_add(hook.inner().sum(), Ordering::Relaxed);
+    /// });
+    /// assert_eq!(total.into_inner(), (0..2000).sum());
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_for_each_hook<E: ExtensionPoint>(&self, f: impl Fn(&hook::Hook<E>) + Sync) {
+        use rayon::prelude::*;
+
+        self.get_enabled_hooks_by_ep::<E>()
+            .into_par_iter()
+            .for_each(|(_id, hook)| f(hook));
+    }
+
+    /// Checks whether a predicate holds for all hooks of enabled [Plugins](Plugin) for a specific
+    /// [`ExtensionPoint`] type.
+    ///
+    /// The hooks are checked in the same order as returned by
+    /// [`get_enabled_hooks_by_ep`](Self::get_enabled_hooks_by_ep), and checking stops at the
+    /// first hook for which `f` returns `false`.
+    ///
+    /// Returns `true` if there are no enabled hooks for the [`ExtensionPoint`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     Validator: ValidatorTrait;
+    ///     fn validate(&self, input: &str) -> bool;
+    /// );
+    ///
+    /// struct NotEmpty;
+    /// impl ValidatorTrait for NotEmpty {
+    ///     fn validate(&self, input: &str) -> bool {
+    ///         !input.is_empty()
+    ///     }
+    /// }
+    ///
+    /// struct Short;
+    /// impl ValidatorTrait for Short {
+    ///     fn validate(&self, input: &str) -> bool {
+    ///         input.len() < 10
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     ValidatorPlugin,
+    ///     "validator_plugin",
+    ///     "Validates input",
+    ///     hooks: [(Validator, NotEmpty, "not_empty"), (Validator, Short, "short")]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ValidatorPlugin::new())).unwrap();
+    /// manager.enable_plugin(ValidatorPlugin::ID).unwrap();
+    ///
+    /// assert!(manager.all_hooks_ok::<Validator>(|hook| hook.inner().validate("hello")));
+    /// assert!(!manager.all_hooks_ok::<Validator>(|hook| hook.inner().validate("")));
+    /// ```
+    #[must_use]
+    pub fn all_hooks_ok<E: ExtensionPoint>(&self, f: impl Fn(&hook::Hook<E>) -> bool) -> bool {
+        self.get_enabled_hooks_by_ep::<E>()
+            .into_iter()
+            .all(|(_id, hook)| f(hook))
+    }
+
+    /// Checks whether a predicate holds for any hook of enabled [Plugins](Plugin) for a specific
+    /// [`ExtensionPoint`] type.
+    ///
+    /// The hooks are checked in the same order as returned by
+    /// [`get_enabled_hooks_by_ep`](Self::get_enabled_hooks_by_ep), and checking stops at the
+    /// first hook for which `f` returns `true`.
+    ///
+    /// Returns `false` if there are no enabled hooks for the [`ExtensionPoint`].
+    ///
+    /// This is the "is there a handler for this?" check before dispatching. To get the handler
+    /// itself, use [`find_first_hook`](Self::find_first_hook).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     Validator: ValidatorTrait;
+    ///     fn validate(&self, input: &str) -> bool;
+    /// );
+    ///
+    /// struct IsNumber;
+    /// impl ValidatorTrait for IsNumber {
+    ///     fn validate(&self, input: &str) -> bool {
+    ///         input.parse::<i64>().is_ok()
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     ValidatorPlugin,
+    ///     "validator_plugin",
+    ///     "Validates input",
+    ///     hooks: [(Validator, IsNumber)]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ValidatorPlugin::new())).unwrap();
+    /// manager.enable_plugin(ValidatorPlugin::ID).unwrap();
+    ///
+    /// assert!(manager.any_hook::<Validator>(|hook| hook.inner().validate("42")));
+    /// assert!(!manager.any_hook::<Validator>(|hook| hook.inner().validate("abc")));
+    /// ```
+    #[must_use]
+    pub fn any_hook<E: ExtensionPoint>(&self, f: impl Fn(&hook::Hook<E>) -> bool) -> bool {
+        self.get_enabled_hooks_by_ep::<E>()
+            .into_iter()
+            .any(|(_id, hook)| f(hook))
+    }
+
+    /// Finds the first hook of enabled [Plugins](Plugin) for a specific [`ExtensionPoint`] type
+    /// that satisfies a predicate.
+    ///
+    /// The hooks are checked in the same order as returned by
+    /// [`get_enabled_hooks_by_ep`](Self::get_enabled_hooks_by_ep), and checking stops at the
+    /// first hook for which `f` returns `true`. This is the dispatching counterpart of
+    /// [`any_hook`](Self::any_hook), e.g. for finding the hook that can handle a command.
+    ///
+    /// Returns [`None`] if no enabled hook satisfies the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     CommandHandler: CommandHandlerTrait;
+    ///     fn can_handle(&self, cmd: &str) -> bool;
+    ///     fn handle(&self, cmd: &str) -> String;
+    /// );
+    ///
+    /// struct Echo;
+    /// impl CommandHandlerTrait for Echo {
+    ///     fn can_handle(&self, cmd: &str) -> bool {
+    ///         cmd.starts_with("echo ")
+    ///     }
+    ///     fn handle(&self, cmd: &str) -> String {
+    ///         cmd["echo ".len()..].to_string()
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     EchoPlugin,
+    ///     "echo_plugin",
+    ///     "Echoes its input",
+    ///     hooks: [(CommandHandler, Echo)]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(EchoPlugin::new())).unwrap();
+    /// manager.enable_plugin(EchoPlugin::ID).unwrap();
+    ///
+    /// let cmd = "echo hello";
+    /// let (id, handler) = manager
+    ///     .find_first_hook::<CommandHandler>(|hook| hook.inner().can_handle(cmd))
+    ///     .unwrap();
+    /// assert_eq!(id.plugin_id, EchoPlugin::ID);
+    /// assert_eq!(handler.inner().handle(cmd), "hello");
+    ///
+    /// assert!(manager
+    ///     .find_first_hook::<CommandHandler>(|hook| hook.inner().can_handle("exit"))
+    ///     .is_none());
+    /// ```
+    #[must_use]
+    pub fn find_first_hook<E: ExtensionPoint>(
+        &self,
+        f: impl Fn(&hook::Hook<E>) -> bool,
+    ) -> Option<(&hook::HookID, &hook::Hook<E>)> {
+        self.get_enabled_hooks_by_ep::<E>()
+            .into_iter()
+            .find(|(_id, hook)| f(hook))
+    }
+
+    /// Calls a function for each hook of enabled [Plugins](Plugin) for a specific
+    /// [`ExtensionPoint`] type, and collects the results by [`HookID`](hook::HookID).
+    ///
+    /// This keeps track of which hook produced which value, e.g. for showing the output of each
+    /// hook in a user interface.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager, hook::HookID};
+    ///
+    /// extension_point!(
+    ///     Health: HealthTrait;
+    ///     fn is_healthy(&self) -> bool;
+    /// );
+    ///
+    /// struct Database;
+    /// impl HealthTrait for Database {
+    ///     fn is_healthy(&self) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// struct Cache;
+    /// impl HealthTrait for Cache {
+    ///     fn is_healthy(&self) -> bool {
+    ///         false
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     HealthPlugin,
+    ///     "health_plugin",
+    ///     "Checks the health of services",
+    ///     hooks: [(Health, Database, "database"), (Health, Cache, "cache")]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(HealthPlugin::new())).unwrap();
+    /// manager.enable_plugin(HealthPlugin::ID).unwrap();
+    ///
+    /// let health = manager.map_enabled_hooks::<Health, _>(|hook| hook.inner().is_healthy());
+    /// assert_eq!(health.len(), 2);
+    /// assert!(health[&HookID::for_ep::<Health>(HealthPlugin::ID, Some("database"))]);
+    /// assert!(!health[&HookID::for_ep::<Health>(HealthPlugin::ID, Some("cache"))]);
+    /// ```
+    #[must_use]
+    pub fn map_enabled_hooks<E: ExtensionPoint, T>(
+        &self,
+        f: impl Fn(&hook::Hook<E>) -> T,
+    ) -> HashMap<hook::HookID, T> {
+        self.get_enabled_hooks_by_ep::<E>()
+            .into_iter()
+            .map(|(id, hook)| (id.clone(), f(hook)))
+            .collect()
+    }
+
+    /// Takes a snapshot of the [shared](hook::Hook#ownership) hooks of enabled [Plugins](Plugin) for
+    /// a specific [`ExtensionPoint`] type.
+    ///
+    /// This is meant for a [`PluginManager`] that is shared between threads behind a lock. The
+    /// snapshot does not borrow the manager, so the lock only needs to be held while the snapshot
+    /// is taken, not while the hooks are called. Calling slow hooks then does not block other
+    /// threads, and the snapshot stays consistent even if plugins are unloaded in the meantime.
+    /// Since it is an [`Arc`](std::sync::Arc), the same snapshot can be handed to many threads cheaply.
+    ///
+    /// Only hooks whose implementation is shared, like those registered with
+    /// [`HookRegistry::register_shared`] or listed as `shared` in [`simple_plugin!`], can outlive
+    /// the borrow of the manager. Hooks that own their implementation are **not** part of the
+    /// snapshot, and a warning is logged for each of them. Register the hooks of extension points
+    /// that are dispatched this way as shared hooks.
+    ///
+    /// The hooks are in the same order as returned by
+    /// [`get_enabled_hooks_by_ep`](Self::get_enabled_hooks_by_ep).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::RwLock;
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     Reader: ReaderTrait;
+    ///     fn read(&self) -> u32;
+    /// );
+    ///
+    /// struct Sensor;
+    /// impl ReaderTrait for Sensor {
+    ///     fn read(&self) -> u32 {
+    ///         // imagine slow I/O here
+    ///         42
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     SensorPlugin,
+    ///     "sensor_plugin",
+    ///     "Reads a sensor",
+    ///     hooks: [shared (Reader, Sensor)]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(SensorPlugin::new())).unwrap();
+    /// manager.enable_plugin(SensorPlugin::ID).unwrap();
+    ///
+    /// let manager = RwLock::new(manager);
+    ///
+    /// // the lock is only held for taking the snapshot
+    /// let snapshot = manager.read().unwrap().snapshot_hooks::<Reader>();
+    ///
+    /// // others can change the manager while the hooks are called
+    /// manager.write().unwrap().unload_plugin(SensorPlugin::ID).unwrap();
+    ///
+    /// assert_eq!(snapshot.len(), 1);
+    /// for (_id, reader) in snapshot.iter() {
+    ///     assert_eq!(reader.read(), 42);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn snapshot_hooks<E: ExtensionPoint>(&self) -> hook::HookSnapshot<E> {
+        self.get_enabled_hooks_by_ep::<E>()
+            .into_iter()
+            .filter_map(|(id, hook)| {
+                let Some(inner) = hook.shared_inner() else {
+                    warn!("Hook {id:?} owns its implementation, leaving it out of the snapshot");
+                    return None;
+                };
+                Some((id.clone(), inner))
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Lists the hooks of enabled [Plugins](Plugin) for a specific [`ExtensionPoint`] type by
+    /// their [name](hook::Hook::name), e.g. to let a user pick one of them.
+    ///
+    /// The entries are sorted by name, and by [`HookID`](hook::HookID) if names are equal, so the
+    /// order is stable for display regardless of the [`SortStrategy`](hook::SortStrategy). The
+    /// [`HookID`](hook::HookID) of the chosen entry can be passed to
+    /// [`invoke_hook`](Self::invoke_hook) or [`HookRegistry::get`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     Theme: ThemeTrait;
+    ///     fn background(&self) -> &'static str;
+    /// );
+    ///
+    /// struct Dark;
+    /// impl ThemeTrait for Dark {
+    ///     fn background(&self) -> &'static str {
+    ///         "black"
+    ///     }
+    /// }
+    ///
+    /// struct Light;
+    /// impl ThemeTrait for Light {
+    ///     fn background(&self) -> &'static str {
+    ///         "white"
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     ThemePlugin,
+    ///     "theme_plugin",
+    ///     "Provides themes",
+    ///     hooks: [(Theme, Light, "light"), (Theme, Dark, "dark")]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ThemePlugin::new())).unwrap();
+    /// manager.enable_plugin(ThemePlugin::ID).unwrap();
+    ///
+    /// // simple_plugin! names the hooks after the type of their implementation
+    /// let menu = manager.hook_menu::<Theme>();
+    /// assert_eq!(menu.len(), 2);
+    /// assert!(menu[0].1.ends_with("Dark"));
+    /// assert!(menu[1].1.ends_with("Light"));
+    ///
+    /// let (chosen, _name) = &menu[0];
+    /// let theme = manager.hook_registry().get::<Theme>(chosen).unwrap();
+    /// assert_eq!(theme.inner().background(), "black");
+    /// ```
+    #[must_use]
+    pub fn hook_menu<E: ExtensionPoint>(&self) -> Vec<(hook::HookID, &str)> {
+        let mut menu: Vec<(hook::HookID, &str)> = self
+            .get_enabled_hooks_by_ep::<E>()
+            .into_iter()
+            .map(|(id, hook)| (id.clone(), hook.name()))
+            .collect();
+        menu.sort_by(|(a_id, a_name), (b_id, b_name)| {
+            a_name.cmp(b_name).then_with(|| a_id.cmp(b_id))
+        });
+        menu
+    }
+
+    /// Sends a message to all enabled [`MessageReceiver`](messaging::MessageReceiver) hooks.
+    ///
+    /// This is a simple publish/subscribe channel between plugins, see the
+    /// [`messaging`] module. The receivers are called in the same order as returned by
+    /// [`get_enabled_hooks_by_ep`](Self::get_enabled_hooks_by_ep).
+    ///
+    /// # Examples
+    ///
+    /// See the [`messaging`] module.
+    pub fn publish(&self, topic: &str, payload: &[u8]) {
+        for (_id, receiver) in self.get_enabled_hooks_by_ep::<messaging::MessageReceiver>() {
+            receiver.inner().receive(topic, payload);
+        }
+    }
+
+    /// Asks the hooks of enabled [Plugins](Plugin) for an [`ExtensionPoint`] one after another,
+    /// until one of them has an answer.
+    ///
+    /// The hooks are asked in the same order as returned by
+    /// [`get_enabled_hooks_by_ep`](Self::get_enabled_hooks_by_ep). The first `Some` returned by
+    /// `f` is the result, and the remaining hooks are not asked anymore. This is the
+    /// chain-of-responsibility pattern, useful for resolver-like extension points.
+    ///
+    /// Returns `None` if `f` returned `None` for all hooks, or if there are no enabled hooks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     IconResolver: IconResolverTrait;
+    ///     fn icon_for(&self, name: &str) -> Option<char>;
+    /// );
+    ///
+    /// struct FolderIcons;
+    /// impl IconResolverTrait for FolderIcons {
+    ///     fn icon_for(&self, name: &str) -> Option<char> {
+    ///         name.ends_with('/').then_some('📁')
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     IconPlugin,
+    ///     "icon_plugin",
+    ///     "Provides icons",
+    ///     hooks: [(IconResolver, FolderIcons)]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(IconPlugin::new())).unwrap();
+    /// manager.enable_plugin(IconPlugin::ID).unwrap();
+    ///
+    /// let resolve = |name| manager.resolve_first::<IconResolver, _>(|hook| hook.inner().icon_for(name));
+    /// assert_eq!(resolve("src/"), Some('📁'));
+    /// assert_eq!(resolve("main.rs"), None);
+    /// ```
+    #[must_use]
+    pub fn resolve_first<E: ExtensionPoint, T>(
+        &self,
+        f: impl Fn(&hook::Hook<E>) -> Option<T>,
+    ) -> Option<T> {
+        self.get_enabled_hooks_by_ep::<E>()
+            .into_iter()
+            .find_map(|(_id, hook)| f(hook))
     }
 
     /// Gets a list of all plugins with their IDs and enabled status.
@@ -1009,18 +5167,622 @@ impl PluginManager {
     /// - A boolean indicating whether the plugin is enabled
     #[must_use]
     pub fn list_plugins(&self) -> Vec<(PluginID, bool)> {
-        self.plugins()
+        self.plugins
             .iter()
-            .map(|plugin| (plugin.id(), plugin.is_enabled()))
+            .map(|(id, plugin)| (*id, plugin.is_enabled()))
             .collect()
     }
+
+    /// Internal helper that checks if a hook is registered, [enabled](HookRegistry::set_hook_enabled)
+    /// and belongs to an enabled plugin.
+    fn hook_is_active(&self, id: &hook::HookID) -> bool {
+        self.plugin_is_enabled(id.plugin_id) == Some(true)
+            && self.hook_registry.is_hook_enabled(id) == Some(true)
+    }
+
+    /// Locks the manager, so that its plugins can no longer be changed.
+    ///
+    /// Use this to harden the plugin configuration after startup. Once locked, loading,
+    /// unloading, swapping, enabling and disabling plugins fails with [`PluginError::Locked`], as
+    /// does rebuilding the hooks with [`rebuild_registry`](Self::rebuild_registry).
+    /// Operations that do not return errors, like
+    /// [`disable_plugin_cascade`](Self::disable_plugin_cascade) and
+    /// [`copy_enabled_state_from`](Self::copy_enabled_state_from), do nothing instead.
+    ///
+    /// Locking is final, there is no way to unlock the manager again. Note that plugins can still
+    /// change themselves through [`get_plugin_mut`](Self::get_plugin_mut) and
+    /// [`for_each_plugin_mut`](Self::for_each_plugin_mut), so do not hand out mutable access to
+    /// the manager if that matters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, error::PluginError, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     ExamplePlugin,
+    ///     "example_plugin",
+    ///     "An example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(ExamplePlugin::new())).unwrap();
+    /// manager.enable_plugin(ExamplePlugin::ID).unwrap();
+    ///
+    /// manager.lock();
+    /// assert!(manager.is_locked());
+    ///
+    /// let result = manager.disable_plugin(ExamplePlugin::ID);
+    /// assert!(matches!(result, Err(PluginError::Locked)));
+    /// let result = manager.unload_plugin(ExamplePlugin::ID);
+    /// assert!(matches!(result, Err(PluginError::Locked)));
+    /// let err = manager.rebuild_registry().unwrap_err();
+    /// assert!(matches!(err.errors()[0].1, PluginError::Locked));
+    /// assert_eq!(manager.plugin_is_enabled(ExamplePlugin::ID), Some(true));
+    /// ```
+    pub fn lock(&mut self) {
+        self.locked = true;
+    }
+
+    /// Returns `true` if the manager was [locked](Self::lock).
+    #[must_use]
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Internal helper that returns [`PluginError::Locked`] if the manager was
+    /// [locked](Self::lock).
+    fn check_unlocked(&self) -> PluginResult<()> {
+        if self.locked {
+            Err(PluginError::Locked)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Turns the manager into a [`FrozenManager`], which can only dispatch hooks.
+    ///
+    /// Use this when the set of plugins is fixed after startup. Hooks of plugins that are not
+    /// loaded and enabled at this point are dropped, as well as hooks that are
+    /// [disabled](HookRegistry::set_hook_enabled), so the frozen manager can dispatch to all
+    /// remaining hooks without checking which plugins are enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{extension_point, simple_plugin, PluginManager};
+    ///
+    /// extension_point!(
+    ///     Greeter: GreeterTrait;
+    ///     fn greet(&self, name: &str) -> String;
+    /// );
+    ///
+    /// struct EnglishGreeter;
+    /// impl GreeterTrait for EnglishGreeter {
+    ///     fn greet(&self, name: &str) -> String {
+    ///         format!("Hello, {}!", name)
+    ///     }
+    /// }
+    ///
+    /// simple_plugin!(
+    ///     HelloPlugin,
+    ///     "hello_plugin",
+    ///     "A simple greeting plugin",
+    ///     hooks: [(Greeter, EnglishGreeter)]
+    /// );
+    /// simple_plugin!(
+    ///     DisabledPlugin,
+    ///     "disabled_plugin",
+    ///     "Is never enabled",
+    ///     hooks: [(Greeter, EnglishGreeter)]
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// manager.load_plugin(Box::new(HelloPlugin::new())).unwrap();
+    /// manager.load_plugin(Box::new(DisabledPlugin::new())).unwrap();
+    /// manager.enable_plugin(HelloPlugin::ID).unwrap();
+    ///
+    /// let frozen = manager.freeze();
+    /// for (_id, hook) in frozen.view_enabled_hooks_by_ep::<Greeter>().iter() {
+    ///     assert_eq!(hook.inner().greet("World"), "Hello, World!");
+    /// }
+    /// assert_eq!(frozen.get_enabled_hooks_by_ep::<Greeter>().len(), 1);
+    /// assert_eq!(frozen.plugin_is_enabled(DisabledPlugin::ID), Some(false));
+    /// ```
+    #[must_use]
+    pub fn freeze(mut self) -> FrozenManager {
+        let inactive: Vec<hook::HookID> = self
+            .hook_registry
+            .get_by_filter(|(id, _hook)| !self.hook_is_active(id))
+            .into_iter()
+            .map(|(id, _hook)| id.clone())
+            .collect();
+        for id in &inactive {
+            self.hook_registry.deregister(id);
+        }
+
+        FrozenManager {
+            plugins: self.plugins,
+            hook_registry: self.hook_registry,
+        }
+    }
+}
+
+/// Unloads a [`Plugin`] from a [`PluginManager`] when dropped.
+///
+/// Created by [`PluginManager::load_scoped`], see there for details. The guard dereferences to the
+/// [`PluginManager`] it borrows.
+///
+/// If unloading the plugin fails, the error is logged, since it cannot be returned from
+/// [`Drop::drop`]. The plugin is removed from the manager either way, see
+/// [`PluginManager::unload_plugin`], even if the manager was [locked](PluginManager::lock) through
+/// the guard in the meantime. If the plugin was already unloaded through the guard, nothing
+/// happens.
+#[derive(Debug)]
+pub struct PluginGuard<'a> {
+    manager: &'a mut PluginManager,
+    /// `None` after [`keep`](Self::keep)
+    plugin_id: Option<PluginID>,
+}
+
+impl PluginGuard<'_> {
+    /// Returns the ID of the guarded plugin.
+    ///
+    /// # Panics
+    ///
+    /// Never panics, the ID is only taken by [`keep`](Self::keep), which consumes the guard.
+    #[must_use]
+    pub fn plugin_id(&self) -> PluginID {
+        self.plugin_id
+            .expect("the plugin id is only taken when the guard is consumed")
+    }
+
+    /// Consumes the guard without unloading the plugin.
+    ///
+    /// Returns the ID of the plugin, which stays loaded in the manager.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, simple_plugin};
+    ///
+    /// simple_plugin!(
+    ///     ExamplePlugin,
+    ///     "example_plugin",
+    ///     "An example plugin",
+    ///     hooks: []
+    /// );
+    ///
+    /// let mut manager = PluginManager::new();
+    /// let guard = manager.load_scoped(Box::new(ExamplePlugin::new())).unwrap();
+    /// let id = guard.keep();
+    ///
+    /// assert!(manager.get_plugin(id).is_some());
+    /// ```
+    #[must_use = "use `drop` to unload the plugin instead"]
+    pub fn keep(mut self) -> PluginID {
+        let id = self.plugin_id();
+        self.plugin_id = None;
+        id
+    }
+}
+
+impl std::ops::Deref for PluginGuard<'_> {
+    type Target = PluginManager;
+
+    fn deref(&self) -> &Self::Target {
+        self.manager
+    }
+}
+
+impl std::ops::DerefMut for PluginGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.manager
+    }
+}
+
+impl Drop for PluginGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(id) = self.plugin_id.take() {
+            if !self.manager.is_loaded(id) {
+                return;
+            }
+            // the plugin was loaded for this scope only, so a lock does not keep it
+            if let Err(e) = self.manager.remove_plugin(id) {
+                error!("Could not unload scoped plugin {id}: {e}");
+            }
+        }
+    }
+}
+
+/// A [`PluginManager`] that can no longer change, created with [`PluginManager::freeze`].
+///
+/// Plugins cannot be loaded, unloaded, enabled or disabled. In exchange, the
+/// [`HookRegistry`] of a frozen manager only contains hooks of enabled plugins, which are
+/// already sorted, so dispatching to them needs neither filtering nor sorting.
+#[derive(Debug)]
+pub struct FrozenManager {
+    plugins: HashMap<PluginID, Box<dyn Plugin>>,
+    hook_registry: HookRegistry,
+}
+
+impl FrozenManager {
+    /// Gets all hooks of enabled plugins for a specific [`ExtensionPoint`] type.
+    ///
+    /// This returns the same hooks as [`PluginManager::get_enabled_hooks_by_ep`] did before
+    /// freezing.
+    #[must_use]
+    pub fn get_enabled_hooks_by_ep<E: ExtensionPoint>(
+        &self,
+    ) -> Vec<(&hook::HookID, &hook::Hook<E>)> {
+        self.hook_registry.get_by_extension_point()
+    }
+
+    /// Gets all hooks of enabled plugins for a specific [`ExtensionPoint`] type, without
+    /// allocating.
+    ///
+    /// See [`HookRegistry::view_by_extension_point`].
+    #[must_use]
+    pub fn view_enabled_hooks_by_ep<E: ExtensionPoint>(&self) -> hook::HookView<'_, E> {
+        self.hook_registry.view_by_extension_point()
+    }
+
+    /// Sends a message to all enabled [`MessageReceiver`](messaging::MessageReceiver) hooks, see
+    /// [`PluginManager::publish`].
+    pub fn publish(&self, topic: &str, payload: &[u8]) {
+        for (_id, receiver) in self
+            .view_enabled_hooks_by_ep::<messaging::MessageReceiver>()
+            .iter()
+        {
+            receiver.inner().receive(topic, payload);
+        }
+    }
+
+    /// Gets a reference to a plugin by ID, if it is loaded.
+    #[must_use]
+    pub fn get_plugin(&self, id: PluginID) -> Option<&dyn Plugin> {
+        self.plugins.get(id).map(std::convert::AsRef::as_ref)
+    }
+
+    /// Gets the IDs of all loaded plugins.
+    #[must_use]
+    pub fn plugin_ids(&self) -> Vec<PluginID> {
+        self.plugins.keys().copied().collect()
+    }
+
+    /// Checks if a plugin is enabled.
+    ///
+    /// Returns [`None`] if the plugin is not loaded.
+    #[must_use]
+    pub fn plugin_is_enabled(&self, id: PluginID) -> Option<bool> {
+        Some(self.plugins.get(id)?.is_enabled())
+    }
+
+    /// Returns a reference to the [`HookRegistry`], which only contains hooks of enabled plugins.
+    #[must_use]
+    pub fn hook_registry(&self) -> &HookRegistry {
+        &self.hook_registry
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::error::HookError;
+    use crate::hook::{Hook, HookID};
+
+    extension_point!(Foo: FooTrait;);
+
+    struct FooHook;
+    impl FooTrait for FooHook {}
+
+    /// A plugin that reports a configurable status after loading
+    #[derive(Debug)]
+    struct StatusPlugin {
+        enabled: bool,
+        status: PluginStatus,
+    }
+
+    impl StatusPlugin {
+        const ID: PluginID = "status_plugin";
+
+        fn new(status: PluginStatus) -> Self {
+            Self {
+                enabled: false,
+                status,
+            }
+        }
+    }
+
+    impl Plugin for StatusPlugin {
+        fn id(&self) -> PluginID {
+            Self::ID
+        }
+        fn description(&self) -> &'static str {
+            "reports a configurable status"
+        }
+        fn is_enabled(&self) -> bool {
+            self.enabled
+        }
+        fn enable(&mut self) {
+            self.enabled = true;
+        }
+        fn disable(&mut self) {
+            self.enabled = false;
+        }
+        fn register_hooks(&self, registry: &mut HookRegistry) -> PluginResult<()> {
+            registry.register(
+                &HookID::new(self.id(), Foo::id(), None),
+                Hook::<Foo>::new(Box::new(FooHook), "foo"),
+            )?;
+            Ok(())
+        }
+        fn status(&self) -> PluginStatus {
+            self.status.clone()
+        }
+    }
+
+    #[test]
+    fn test_load_failed_status() {
+        let mut manager = PluginManager::new();
+        let err = manager
+            .load_plugin(Box::new(StatusPlugin::new(PluginStatus::Failed(
+                "missing configuration".into(),
+            ))))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            PluginError::LoadedButFailed { ref plugin, ref reason }
+                if plugin.id() == StatusPlugin::ID && reason == "missing configuration"
+        ));
+        assert!(manager.get_plugin(StatusPlugin::ID).is_none());
+        assert!(manager
+            .hook_registry()
+            .get_by_plugin(StatusPlugin::ID)
+            .is_empty());
+        assert_eq!(manager.plugin_state(StatusPlugin::ID), PluginState::Failed);
+
+        // a successful load clears the record
+        manager
+            .load_plugin(Box::new(StatusPlugin::new(PluginStatus::Ok)))
+            .unwrap();
+        assert!(manager.failed_loads().is_empty());
+        assert_eq!(manager.plugin_state(StatusPlugin::ID), PluginState::Loaded);
+    }
+
+    #[test]
+    fn test_swap_plugin_restores_on_failure() {
+        let mut manager = PluginManager::new();
+        manager
+            .load_plugin(Box::new(StatusPlugin::new(PluginStatus::Ok)))
+            .unwrap();
+        manager.enable_plugin(StatusPlugin::ID).unwrap();
+
+        let err = manager
+            .swap_plugin(Box::new(StatusPlugin::new(PluginStatus::Failed(
+                "broken".into(),
+            ))))
+            .unwrap_err();
+        assert!(matches!(err, PluginError::LoadedButFailed { .. }));
+        // the old plugin is still there, as it was
+        assert_eq!(manager.plugin_is_enabled(StatusPlugin::ID), Some(true));
+        assert_eq!(
+            manager
+                .hook_registry()
+                .get_by_plugin(StatusPlugin::ID)
+                .len(),
+            1
+        );
+
+        let old = manager
+            .swap_plugin(Box::new(StatusPlugin::new(PluginStatus::Ok)))
+            .unwrap();
+        assert!(old.is_enabled());
+        assert_eq!(manager.plugin_is_enabled(StatusPlugin::ID), Some(true));
+        assert_eq!(
+            manager
+                .hook_registry()
+                .get_by_plugin(StatusPlugin::ID)
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_scoped_plugin_unloaded_once() {
+        let mut manager = PluginManager::new();
+        {
+            let mut guard = manager
+                .load_scoped(Box::new(StatusPlugin::new(PluginStatus::Ok)))
+                .unwrap();
+            guard.lock();
+        }
+        assert!(!manager.is_loaded(StatusPlugin::ID));
+        assert!(manager.hook_registry().is_empty());
+
+        let mut manager = PluginManager::new();
+        {
+            let mut guard = manager
+                .load_scoped(Box::new(StatusPlugin::new(PluginStatus::Ok)))
+                .unwrap();
+            guard.unload_plugin(StatusPlugin::ID).unwrap();
+            // nothing is left for the guard to unload
+        }
+        assert!(!manager.is_loaded(StatusPlugin::ID));
+    }
+
+    #[test]
+    fn test_rebuild_locked_registry_fails() {
+        let mut manager = PluginManager::new();
+        manager.lock();
+        let err = manager.rebuild_registry().unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert!(matches!(err.errors()[0].1, PluginError::Locked));
+
+        let mut manager = PluginManager::new();
+        manager
+            .load_plugin(Box::new(StatusPlugin::new(PluginStatus::Ok)))
+            .unwrap();
+        manager.lock();
+        let err = manager.rebuild_registry().unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert_eq!(err.errors()[0].0.id(), StatusPlugin::ID);
+        assert_eq!(manager.hook_registry().len(), 1);
+    }
+
+    #[test]
+    fn test_failed_unload_removes_hooks() {
+        #[derive(Debug)]
+        struct StuckPlugin;
+
+        impl Plugin for StuckPlugin {
+            fn id(&self) -> PluginID {
+                "stuck_plugin"
+            }
+            fn description(&self) -> &'static str {
+                "fails to unload"
+            }
+            fn is_enabled(&self) -> bool {
+                true
+            }
+            fn enable(&mut self) {}
+            fn disable(&mut self) {}
+            fn register_hooks(&self, registry: &mut HookRegistry) -> PluginResult<()> {
+                registry.register(
+                    &HookID::new(self.id(), Foo::id(), None),
+                    Hook::<Foo>::new(Box::new(FooHook), "foo"),
+                )?;
+                Ok(())
+            }
+            fn on_unload(&mut self) -> PluginResult<()> {
+                Err(PluginError::NotFound("stuck_resource".into()))
+            }
+        }
+
+        let mut manager = PluginManager::new();
+        manager.load_plugin(Box::new(StuckPlugin)).unwrap();
+        assert!(manager.unload_plugin("stuck_plugin").is_err());
+        assert!(!manager.is_loaded("stuck_plugin"));
+        assert!(manager.hook_registry().is_empty());
+    }
+
+    #[test]
+    fn test_closed_extension_point_keeps_hooks() {
+        let hooks = |manager: &PluginManager| {
+            manager
+                .hook_registry()
+                .get_by_extension_point::<Foo>()
+                .len()
+        };
+
+        let mut manager = PluginManager::new();
+        manager
+            .load_plugin(Box::new(StatusPlugin::new(PluginStatus::Ok)))
+            .unwrap();
+        manager.hook_registry_mut().close_extension_point::<Foo>();
+
+        manager.rebuild_registry().unwrap();
+        assert_eq!(hooks(&manager), 1);
+
+        // the new plugin may not register, but the old one is restored
+        let result = manager.reload_plugin(Box::new(StatusPlugin::new(PluginStatus::Ok)));
+        assert!(matches!(
+            result,
+            Err(PluginError::HookError(HookError::ExtensionPointClosed))
+        ));
+        assert!(manager.is_loaded(StatusPlugin::ID));
+        assert_eq!(hooks(&manager), 1);
+        assert!(manager.hook_registry().is_extension_point_closed::<Foo>());
+    }
+
+    #[test]
+    fn test_simple_plugin_skips_explicit_discriminators() {
+        struct First;
+        impl FooTrait for First {}
+        struct Second;
+        impl FooTrait for Second {}
+        struct Third;
+        impl FooTrait for Third {}
+
+        simple_plugin!(
+            MixedPlugin,
+            "mixed_plugin",
+            "Mixes automatic and explicit discriminators",
+            hooks: [(Foo, First), (Foo, Second), (Foo, Third, "1")]
+        );
+
+        let mut registry = HookRegistry::new();
+        MixedPlugin::new().register_hooks(&mut registry).unwrap();
+        assert_eq!(registry.count_for_extension_point::<Foo>(), 3);
+
+        let name_of = |discriminator| {
+            let id = HookID::for_ep::<Foo>(MixedPlugin::ID, discriminator);
+            registry.get::<Foo>(&id).unwrap().name().to_string()
+        };
+        assert!(name_of(None).ends_with("First"));
+        assert!(name_of(Some("2")).ends_with("Second"));
+        assert!(name_of(Some("1")).ends_with("Third"));
+    }
+
+    #[test]
+    fn test_for_each_hook_mut_accumulates() {
+        extension_point!(
+            Metrics: MetricsTrait;
+            fn observe(&mut self, value: u64);
+            fn count(&self) -> usize;
+        );
+
+        #[derive(Default)]
+        struct Collector {
+            values: Vec<u64>,
+        }
+        impl MetricsTrait for Collector {
+            fn observe(&mut self, value: u64) {
+                self.values.push(value);
+            }
+            fn count(&self) -> usize {
+                self.values.len()
+            }
+        }
+
+        simple_plugin!(
+            MetricsPlugin,
+            "metrics_plugin",
+            "Collects metrics",
+            hooks: [(Metrics, Collector::default())]
+        );
+
+        let count = |manager: &PluginManager| {
+            manager
+                .hook_registry()
+                .get_by_extension_point::<Metrics>()
+                .iter()
+                .map(|(_id, hook)| hook.inner().count())
+                .sum::<usize>()
+        };
+
+        let mut manager = PluginManager::new();
+        manager.load_plugin(Box::new(MetricsPlugin::new())).unwrap();
+        manager.enable_plugin(MetricsPlugin::ID).unwrap();
+
+        for value in 0..3 {
+            manager.for_each_hook_mut::<Metrics>(|hook| hook.inner_mut().observe(value));
+        }
+        assert_eq!(count(&manager), 3);
+
+        // disabled plugins are skipped, but keep their state
+        manager.disable_plugin(MetricsPlugin::ID).unwrap();
+        manager.for_each_hook_mut::<Metrics>(|hook| hook.inner_mut().observe(42));
+        assert_eq!(count(&manager), 3);
+
+        manager.enable_plugin(MetricsPlugin::ID).unwrap();
+        manager.for_each_hook_mut::<Metrics>(|hook| hook.inner_mut().observe(42));
+        assert_eq!(count(&manager), 4);
+    }
 
     #[test]
+    #[cfg(feature = "serde")]
     fn test_ser_dser_pluginid() {
         let some_id: PluginID = "foo";
         let oid = PluginIDOwned::from(some_id);
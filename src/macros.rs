@@ -8,6 +8,9 @@
 //! - [`extension_point!`](crate::extension_point): Defines a new extension point and its associated trait
 //! - [`simple_plugin!`](crate::simple_plugin): Creates a simple plugin implementation with minimal boilerplate
 //! - [`register_hook!`](crate::register_hook): Registers a hook with a hook registry
+//! - [`for_each_ep!`](crate::for_each_ep): Iterates over the hooks of several extension points
+//! - [`extension_point_data!`](crate::extension_point_data): Defines argument and result structs
+//!   for extension point methods that can grow without breaking hooks
 //!
 //! These macros reduce the amount of boilerplate code needed to work with the
 //! steckrs plugin system, making it easier to define and use plugins.
@@ -25,6 +28,8 @@
 /// - `$name`: The name of the extension point struct
 /// - `$trait_meta`: Attributes for the trait, like documentation and derives
 /// - `$trait_name`: The name of the trait that hooks will implement
+/// - `docs = $docs` (optional): Sets [`ExtensionPoint::DOCS`](crate::hook::ExtensionPoint::DOCS),
+///   for example to a URL of the extension point's specification
 /// - `$($fn_sig:tt)*`: The function signatures for the trait
 ///
 /// # Examples
@@ -50,6 +55,13 @@
 ///     fn supports_format(&self, format_name: &str) -> bool;
 /// );
 ///
+/// // Define an extension point that links to its documentation
+/// extension_point!(
+///     Parser: ParserTrait, docs = "https://example.com/specs/parser";
+///     fn parse(&self, input: &str) -> Vec<String>;
+/// );
+/// assert_eq!(Parser::DOCS, Some("https://example.com/specs/parser"));
+///
 /// // Implement the trait for a concrete type
 /// struct ConsoleLogger;
 /// impl LoggerTrait for ConsoleLogger {
@@ -62,13 +74,20 @@
 /// let hook = Hook::<Logger>::new(Box::new(ConsoleLogger), "myhook");
 /// hook.inner().log("Hello from hook!");
 /// ```
+///
+/// # Evolving Signatures
+///
+/// Every hook implements the methods of the trait, so adding a parameter to a method breaks all
+/// hooks. If the methods of an extension point are likely to change, let them take a single
+/// argument struct and return a result struct, which can get new fields instead. See
+/// [`extension_point_data!`](crate::extension_point_data) for defining such structs.
 #[macro_export]
 macro_rules! extension_point {
     (
     $(#[$name_meta:meta])*
     $name:ident:
     $(#[$trait_meta:meta])*
-    $trait_name:ident;
+    $trait_name:ident $(, docs = $docs:expr)?;
         $($trait_item:tt)*
     ) => {
         $(#[$trait_meta])*
@@ -84,6 +103,7 @@ macro_rules! extension_point {
 
         impl $crate::hook::ExtensionPoint for $name {
             type HookTrait = dyn $trait_name;
+            $(const DOCS: Option<&'static str> = Some($docs);)?
         }
     };
 }
@@ -104,6 +124,15 @@ macro_rules! extension_point {
 /// - `$description`: A description of the plugin (as a string literal)
 /// - `hooks: [($ext_point:ty, $hook_impl:ty)]`: A list of extension point and hook implementation pairs
 ///
+/// Each entry in the `hooks` list may have attributes, like `#[cfg(...)]`, which are applied to
+/// the registration of that hook. This allows registering a hook only if a feature is enabled.
+///
+/// An entry may also be marked as `optional`, in which case `$hook_impl` must be a [`Result`] of
+/// the hook implementation. If it is an [`Err`], the hook is skipped, see
+/// [`HookRegistry::register_optional`](crate::hook::HookRegistry::register_optional). An entry
+/// marked as `shared` is registered with a [shared](crate::hook::Hook#ownership) implementation
+/// instead.
+///
 /// # Examples
 ///
 /// ```
@@ -162,24 +191,261 @@ macro_rules! extension_point {
 ///     "greeting_plugin",
 ///     "A plugin with multiple greeting implementations",
 ///     hooks: [
-///         (Greeter, CasualGreeter, "casual"), // if there are two hooks for an extension point
-///         (Greeter, FormalGreeter, "formal"), // you need to add a discriminant
+///         (Greeter, CasualGreeter, "casual"), // discriminators tell apart hooks
+///         (Greeter, FormalGreeter, "formal"), // for the same extension point
 ///         (Farewell, SimpleFarewell)
 ///     ]
 /// );
 /// ```
 ///
+/// Entries without a discriminator for an extension point that already has a hook of the plugin
+/// get one automatically, see
+/// [`HookRegistry::free_discriminator`](crate::hook::HookRegistry::free_discriminator). The
+/// first such hook has no discriminator, the following ones are numbered in the order they are
+/// listed, starting at `"1"`. Numbers that are given to other hooks of the plugin explicitly are
+/// skipped:
+///
+/// ```
+/// # use steckrs::{extension_point, simple_plugin, Plugin, hook::{HookID, HookRegistry}};
+/// # extension_point!(
+/// #     Greeter: GreeterTrait;
+/// #     fn greet(&self, name: &str) -> String;
+/// # );
+/// # struct FormalGreeter;
+/// # impl GreeterTrait for FormalGreeter {
+/// #     fn greet(&self, name: &str) -> String {
+/// #         format!("Good day, {}!", name)
+/// #     }
+/// # }
+/// # struct CasualGreeter;
+/// # impl GreeterTrait for CasualGreeter {
+/// #     fn greet(&self, name: &str) -> String {
+/// #         format!("Hey {}!", name)
+/// #     }
+/// # }
+/// simple_plugin!(
+///     GreetingPlugin,
+///     "greeting_plugin",
+///     "Greets in two ways",
+///     hooks: [(Greeter, CasualGreeter), (Greeter, FormalGreeter)]
+/// );
+///
+/// let mut registry = HookRegistry::new();
+/// GreetingPlugin::new().register_hooks(&mut registry).unwrap();
+///
+/// let casual = HookID::for_ep::<Greeter>(GreetingPlugin::ID, None);
+/// let formal = HookID::for_ep::<Greeter>(GreetingPlugin::ID, Some("1"));
+/// assert_eq!(registry.get::<Greeter>(&casual).unwrap().inner().greet("Bob"), "Hey Bob!");
+/// assert_eq!(registry.get::<Greeter>(&formal).unwrap().inner().greet("Bob"), "Good day, Bob!");
+/// ```
+///
+/// Hooks can be registered conditionally with `#[cfg(...)]` attributes:
+///
+/// ```
+/// # use steckrs::{extension_point, simple_plugin, Plugin, hook::HookRegistry};
+/// # extension_point!(
+/// #     Greeter: GreeterTrait;
+/// #     fn greet(&self, name: &str) -> String;
+/// # );
+/// # struct FormalGreeter;
+/// # impl GreeterTrait for FormalGreeter {
+/// #     fn greet(&self, name: &str) -> String {
+/// #         format!("Good day, {}!", name)
+/// #     }
+/// # }
+/// # struct FancyGreeter;
+/// # impl GreeterTrait for FancyGreeter {
+/// #     fn greet(&self, name: &str) -> String {
+/// #         format!("A most splendid day to you, {}!", name)
+/// #     }
+/// # }
+/// simple_plugin!(
+///     GreetingPlugin,
+///     "greeting_plugin",
+///     "A plugin that greets fancier if the feature is enabled",
+///     hooks: [
+///         #[cfg(not(feature = "fancy"))]
+///         (Greeter, FormalGreeter),
+///         #[cfg(feature = "fancy")]
+///         (Greeter, FancyGreeter),
+///     ]
+/// );
+///
+/// let mut registry = HookRegistry::new();
+/// GreetingPlugin::new().register_hooks(&mut registry).unwrap();
+/// assert_eq!(registry.get_by_extension_point::<Greeter>().len(), 1);
+/// ```
+///
+/// Hooks that may fail to construct can be marked as `optional`:
+///
+/// ```
+/// # use steckrs::{extension_point, simple_plugin, Plugin, hook::HookRegistry};
+/// # extension_point!(
+/// #     Greeter: GreeterTrait;
+/// #     fn greet(&self, name: &str) -> String;
+/// # );
+/// # struct FormalGreeter;
+/// # impl GreeterTrait for FormalGreeter {
+/// #     fn greet(&self, name: &str) -> String {
+/// #         format!("Good day, {}!", name)
+/// #     }
+/// # }
+/// struct LocalizedGreeter {
+///     greeting: String,
+/// }
+/// impl LocalizedGreeter {
+///     fn from_env() -> Result<Self, std::env::VarError> {
+///         let greeting = std::env::var("STECKRS_EXAMPLE_GREETING")?;
+///         Ok(Self { greeting })
+///     }
+/// }
+/// impl GreeterTrait for LocalizedGreeter {
+///     fn greet(&self, name: &str) -> String {
+///         format!("{}, {}!", self.greeting, name)
+///     }
+/// }
+///
+/// simple_plugin!(
+///     GreetingPlugin,
+///     "greeting_plugin",
+///     "Greets in the local language, if configured",
+///     hooks: [
+///         (Greeter, FormalGreeter, "formal"),
+///         optional (Greeter, LocalizedGreeter::from_env(), "localized"),
+///     ]
+/// );
+///
+/// let mut registry = HookRegistry::new();
+/// GreetingPlugin::new().register_hooks(&mut registry).unwrap();
+/// // the localized greeter is skipped, since the variable is not set
+/// assert_eq!(registry.get_by_extension_point::<Greeter>().len(), 1);
+/// ```
+///
+/// Hooks marked as `shared` keep their implementation in an [`Arc`](std::sync::Arc), see
+/// [Ownership](crate::hook::Hook#ownership). Only such hooks can be taken out of the manager with
+/// [`PluginManager::snapshot_hooks`](crate::PluginManager::snapshot_hooks):
+///
+/// ```
+/// # use steckrs::{extension_point, simple_plugin, PluginManager};
+/// # extension_point!(
+/// #     Greeter: GreeterTrait;
+/// #     fn greet(&self, name: &str) -> String;
+/// # );
+/// # struct FormalGreeter;
+/// # impl GreeterTrait for FormalGreeter {
+/// #     fn greet(&self, name: &str) -> String {
+/// #         format!("Good day, {}!", name)
+/// #     }
+/// # }
+/// simple_plugin!(
+///     GreetingPlugin,
+///     "greeting_plugin",
+///     "Greets from anywhere",
+///     hooks: [shared (Greeter, FormalGreeter)]
+/// );
+///
+/// let mut manager = PluginManager::new();
+/// manager.load_plugin(Box::new(GreetingPlugin::new())).unwrap();
+/// manager.enable_plugin(GreetingPlugin::ID).unwrap();
+///
+/// let snapshot = manager.snapshot_hooks::<Greeter>();
+/// drop(manager);
+/// assert_eq!(snapshot[0].1.greet("Bob"), "Good day, Bob!");
+/// ```
+///
+/// Hooks can be given a [priority](crate::hook::Hook::with_priority) with `; priority = N` at
+/// the end of the entry. Hooks without a priority have priority `0`:
+///
+/// ```
+/// # use steckrs::{extension_point, simple_plugin, Plugin, hook::HookRegistry};
+/// # extension_point!(
+/// #     Greeter: GreeterTrait;
+/// #     fn greet(&self, name: &str) -> String;
+/// # );
+/// # struct FormalGreeter;
+/// # impl GreeterTrait for FormalGreeter {
+/// #     fn greet(&self, name: &str) -> String {
+/// #         format!("Good day, {}!", name)
+/// #     }
+/// # }
+/// # struct CasualGreeter;
+/// # impl GreeterTrait for CasualGreeter {
+/// #     fn greet(&self, name: &str) -> String {
+/// #         format!("Hey {}!", name)
+/// #     }
+/// # }
+/// simple_plugin!(
+///     GreetingPlugin,
+///     "greeting_plugin",
+///     "Prefers to greet casually",
+///     hooks: [
+///         (Greeter, FormalGreeter, "formal"),
+///         (Greeter, CasualGreeter, "casual"; priority = 10),
+///     ]
+/// );
+///
+/// let mut registry = HookRegistry::new();
+/// GreetingPlugin::new().register_hooks(&mut registry).unwrap();
+/// let hooks = registry.get_by_extension_point::<Greeter>();
+/// assert_eq!(hooks[0].1.inner().greet("World"), "Hey World!");
+/// ```
+///
 /// # Panics
 ///
 /// The generated [`register_hooks`](crate::Plugin::register_hooks) method may panic if hook registration fails.
 #[macro_export]
 macro_rules! simple_plugin {
+    (@hook $registry:ident, $reserved:ident, [] $extension_point:ident, $hook_impl:expr $(; priority = $priority:expr)?) => {
+        let discrim = $registry.free_discriminator::<$extension_point>(Self::ID, $reserved);
+        $crate::register_hook!(@register $registry, Self::ID, $extension_point, $hook_impl, discrim.as_deref() $(, $priority)?);
+    };
+    (@hook $registry:ident, $reserved:ident, [] $extension_point:ident, $hook_impl:expr, $discrim:expr $(; priority = $priority:expr)?) => {
+        $crate::register_hook!($registry, Self::ID, $extension_point, $hook_impl, $discrim $(; priority = $priority)?);
+    };
+    (@hook $registry:ident, $reserved:ident, [optional] $extension_point:ident, $hook_impl:expr $(; priority = $priority:expr)?) => {
+        let discrim = $registry.free_discriminator::<$extension_point>(Self::ID, $reserved);
+        $crate::simple_plugin!(@optional $registry, $extension_point, $hook_impl, discrim.as_deref() $(, $priority)?);
+    };
+    (@hook $registry:ident, $reserved:ident, [optional] $extension_point:ident, $hook_impl:expr, $discrim:expr $(; priority = $priority:expr)?) => {
+        $crate::simple_plugin!(@optional $registry, $extension_point, $hook_impl, Some($discrim) $(, $priority)?);
+    };
+    (@hook $registry:ident, $reserved:ident, [shared] $extension_point:ident, $hook_impl:expr $(; priority = $priority:expr)?) => {
+        let discrim = $registry.free_discriminator::<$extension_point>(Self::ID, $reserved);
+        $crate::simple_plugin!(@shared $registry, $extension_point, $hook_impl, discrim.as_deref() $(, $priority)?);
+    };
+    (@hook $registry:ident, $reserved:ident, [shared] $extension_point:ident, $hook_impl:expr, $discrim:expr $(; priority = $priority:expr)?) => {
+        $crate::simple_plugin!(@shared $registry, $extension_point, $hook_impl, Some($discrim) $(, $priority)?);
+    };
+    (@shared $registry:ident, $extension_point:ident, $hook_impl:expr, $discrim:expr $(, $priority:expr)?) => {
+        let hook = $hook_impl;
+        let name = std::any::type_name_of_val(&hook);
+        $registry
+            .register(
+                &$crate::hook::HookID::for_ep::<$extension_point>(Self::ID, $discrim),
+                $crate::hook::Hook::<$extension_point>::new_shared(std::sync::Arc::new(hook), name)
+                    $(.with_priority($priority))?,
+            )
+            .expect("could not register hook");
+    };
+    (@optional $registry:ident, $extension_point:ident, $hook_impl:expr, $discrim:expr $(, $priority:expr)?) => {
+        let hook = ($hook_impl).map(|hook| {
+            let name = std::any::type_name_of_val(&hook);
+            $crate::hook::Hook::<$extension_point>::new(Box::new(hook), name)
+                $(.with_priority($priority))?
+        });
+        $registry
+            .register_optional(
+                &$crate::hook::HookID::for_ep::<$extension_point>(Self::ID, $discrim),
+                hook,
+            )
+            .expect("could not register hook");
+    };
         (
         $(#[$plugin_meta:meta])*
         $plugin_name:ident,
         $plugin_id:expr,
         $description:expr,
-        hooks: [$(($extension_point:ident, $hook_impl:expr $(, $discrim:expr)?)),* $(,)?]) => {
+        hooks: [$($(#[$hook_meta:meta])* $($optional:ident)? ($extension_point:ident, $hook_impl:expr $(, $discrim:expr)? $(; priority = $priority:expr)?)),* $(,)?]) => {
         $(#[$plugin_meta])*
         #[derive(Debug)]
         pub struct $plugin_name {
@@ -221,8 +487,14 @@ macro_rules! simple_plugin {
 
 
             fn register_hooks(&self, registry: &mut $crate::hook::HookRegistry) -> $crate::error::PluginResult<()> {
+                // automatic discriminators must not clash with the explicit ones
+                #[allow(unused_variables)]
+                let reserved: &[&str] = &[$($($discrim,)?)*];
                 $(
-                    $crate::register_hook!(registry, Self::ID, $extension_point, $hook_impl $(, $discrim)?);
+                    $(#[$hook_meta])*
+                    {
+                        $crate::simple_plugin!(@hook registry, reserved, [$($optional)?] $extension_point, $hook_impl $(, $discrim)? $(; priority = $priority)?);
+                    }
                 )*
 
                 Ok(())
@@ -244,6 +516,8 @@ macro_rules! simple_plugin {
 /// - `$discriminator`: An optional discriminator (or `None`)
 /// - `$hook_trait`: The trait type for the hook
 /// - `$hook_impl`: The implementation type for the hook
+/// - `$priority`: An optional [priority](crate::hook::Hook::with_priority), given as
+///   `; priority = N` after the other parameters, `0` if left out
 ///
 /// # Panics
 ///
@@ -284,37 +558,219 @@ macro_rules! simple_plugin {
 /// let hooks = registry.get_by_extension_point::<Calculator>();
 /// assert_eq!(hooks.len(), 1);
 /// assert_eq!(hooks[0].1.inner().add(2, 3), 5);
+///
+/// // Register a hook that comes before the others
+/// register_hook!(
+///     registry,
+///     "calculator_plugin",
+///     Calculator,
+///     SimpleCalculator,
+///     "preferred";
+///     priority = 10
+/// );
+/// let hooks = registry.get_by_extension_point::<Calculator>();
+/// assert_eq!(hooks[0].1.priority(), 10);
 /// ```
 #[macro_export]
 macro_rules! register_hook {
-    ($registry_mut:expr, $plugin_id:expr, $extension_point:ident, $hook:expr) => {
+    (@register $registry_mut:expr, $plugin_id:expr, $extension_point:ident, $hook:expr, $discriminator:expr $(, $priority:expr)?) => {
         $registry_mut
             .register(
-                &$crate::hook::HookID::new(
-                    $plugin_id,
-                    <$extension_point as $crate::hook::ExtensionPoint>::id(),
-                    None,
-                ),
+                &$crate::hook::HookID::for_ep::<$extension_point>($plugin_id, $discriminator),
                 $crate::hook::Hook::<$extension_point>::new(
                     Box::new($hook),
                     std::any::type_name_of_val(&$hook),
-                ),
+                )
+                $(.with_priority($priority))?,
             )
             .expect("could not register hook")
     };
-    ($registry_mut:expr, $plugin_id:expr, $extension_point:ident, $hook:expr, $discriminator:expr) => {
-        $registry_mut
-            .register(
-                &$crate::hook::HookID::new(
-                    $plugin_id,
-                    <$extension_point as $crate::hook::ExtensionPoint>::id(),
-                    Some($discriminator),
-                ),
-                $crate::hook::Hook::<$extension_point>::new(
-                    Box::new($hook),
-                    std::any::type_name_of_val(&$hook),
-                ),
-            )
-            .expect("could not register hook")
+    ($registry_mut:expr, $plugin_id:expr, $extension_point:ident, $hook:expr $(; priority = $priority:expr)?) => {
+        $crate::register_hook!(@register $registry_mut, $plugin_id, $extension_point, $hook, None $(, $priority)?)
+    };
+    ($registry_mut:expr, $plugin_id:expr, $extension_point:ident, $hook:expr, $discriminator:expr $(; priority = $priority:expr)?) => {
+        $crate::register_hook!(@register $registry_mut, $plugin_id, $extension_point, $hook, Some($discriminator) $(, $priority)?)
+    };
+}
+
+/// Calls a closure for every hook of several [Extension Points](crate::hook::ExtensionPoint).
+///
+/// Each extension point is its own type, so their hooks can not be collected into one typed list.
+/// This macro expands to one loop per listed extension point instead, and passes each hook to the
+/// closure as a type-erased [`BoxedHook`](crate::hook::BoxedHook) together with its
+/// [`HookID`](crate::hook::HookID). Use [`BoxedHook::downcast`](crate::hook::BoxedHook::downcast)
+/// to get a typed hook back where needed.
+///
+/// The extension points are visited in the listed order, the hooks of each extension point in
+/// the order of [`HookRegistry::get_by_filter`](crate::hook::HookRegistry::get_by_filter).
+///
+/// # Parameters
+///
+/// - `$registry`: The [`HookRegistry`](crate::hook::HookRegistry) to read the hooks from
+/// - `$extension_point`: A list of extension point types
+/// - `$f`: A closure taking a `&HookID` and a `&BoxedHook`
+///
+/// # Examples
+///
+/// ```
+/// use steckrs::{
+///     extension_point, for_each_ep,
+///     hook::{ExtensionPoint, HookRegistry},
+///     register_hook,
+/// };
+///
+/// extension_point!(
+///     Cpu: CpuTrait;
+///     fn load(&self) -> f32;
+/// );
+///
+/// extension_point!(
+///     Memory: MemoryTrait;
+///     fn used(&self) -> u64;
+/// );
+///
+/// struct CpuMonitor;
+/// impl CpuTrait for CpuMonitor {
+///     fn load(&self) -> f32 {
+///         0.5
+///     }
+/// }
+///
+/// struct MemoryMonitor;
+/// impl MemoryTrait for MemoryMonitor {
+///     fn used(&self) -> u64 {
+///         1024
+///     }
+/// }
+///
+/// let mut registry = HookRegistry::new();
+/// register_hook!(registry, "monitoring", Cpu, CpuMonitor);
+/// register_hook!(registry, "monitoring", Memory, MemoryMonitor);
+///
+/// let mut names = Vec::new();
+/// for_each_ep!(registry, [Cpu, Memory], |_id, hook| {
+///     names.push(hook.name().to_string());
+/// });
+///
+/// assert_eq!(names.len(), 2);
+/// assert!(names[0].contains("CpuMonitor"));
+/// assert!(names[1].contains("MemoryMonitor"));
+/// ```
+#[macro_export]
+macro_rules! for_each_ep {
+    ($registry:expr, [$($extension_point:ty),* $(,)?], $f:expr) => {{
+        let registry: &$crate::hook::HookRegistry = &$registry;
+        let f: &mut dyn FnMut(&$crate::hook::HookID, &$crate::hook::BoxedHook) = &mut $f;
+        $(
+            for (id, hook) in registry.get_by_filter(|(id, _)| {
+                id.extension_point_id
+                    == <$extension_point as $crate::hook::ExtensionPoint>::id()
+            }) {
+                f(id, hook);
+            }
+        )*
+    }};
+}
+
+/// Defines a struct for the arguments or the result of an
+/// [`ExtensionPoint`](crate::hook::ExtensionPoint) method, which can get new fields without
+/// breaking the hooks of other crates.
+///
+/// Hooks usually live in plugin crates, while the extension point is defined by the host. If a
+/// method of the extension point takes all arguments as one struct, and returns one struct, the
+/// host can add fields to these structs without changing the signature of the method.
+///
+/// This macro generates:
+/// - The struct, with the given fields, attributes and visibility
+/// - `#[non_exhaustive]`, so other crates cannot rely on the struct having exactly these fields
+/// - Implementations of [`Debug`], [`Clone`] and [`Default`]
+/// - A builder-style setter for every field, with the same name as the field
+///
+/// Since other crates cannot use struct literals for a `#[non_exhaustive]` struct, they create
+/// it with [`Default::default`] and the setters. New fields therefore need a sensible default.
+///
+/// # Parameters
+///
+/// - `$meta`: Attributes for the struct, like documentation
+/// - `$vis`: The visibility of the struct and its setters
+/// - `$name`: The name of the struct
+/// - `$field_meta`, `$field_vis`, `$field`, `$ty`: Attributes, visibility, name and type of each
+///   field. Each type must implement [`Debug`], [`Clone`] and [`Default`].
+///
+/// # Examples
+///
+/// ```
+/// use steckrs::{extension_point, extension_point_data};
+///
+/// extension_point_data!(
+///     /// Arguments of [`CommandHandlerTrait::handle`]
+///     pub struct HandleArgs {
+///         /// The command to run
+///         pub command: String,
+///         /// Whether to print details
+///         pub verbose: bool,
+///     }
+/// );
+///
+/// extension_point_data!(
+///     /// Result of [`CommandHandlerTrait::handle`]
+///     pub struct HandleResult {
+///         /// Whether the command was handled
+///         pub handled: bool,
+///         /// The output of the command
+///         pub output: String,
+///     }
+/// );
+///
+/// extension_point!(
+///     CommandHandler: CommandHandlerTrait;
+///     fn handle(&self, args: HandleArgs) -> HandleResult;
+/// );
+///
+/// struct EchoHandler;
+/// impl CommandHandlerTrait for EchoHandler {
+///     fn handle(&self, args: HandleArgs) -> HandleResult {
+///         HandleResult::default()
+///             .handled(true)
+///             .output(args.command)
+///     }
+/// }
+///
+/// let args = HandleArgs::default().command("echo".to_string());
+/// let result = EchoHandler.handle(args);
+/// assert!(result.handled);
+/// assert_eq!(result.output, "echo");
+/// ```
+#[macro_export]
+macro_rules! extension_point_data {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $ty:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default)]
+        #[non_exhaustive]
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $ty,
+            )*
+        }
+
+        impl $name {
+            $(
+                #[doc = concat!("Sets `", stringify!($field), "`.")]
+                #[must_use]
+                $vis fn $field(mut self, $field: $ty) -> Self {
+                    self.$field = $field;
+                    self
+                }
+            )*
+        }
     };
 }
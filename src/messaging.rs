@@ -0,0 +1,59 @@
+//! # Messaging
+//!
+//! This module provides a built-in [`ExtensionPoint`](crate::hook::ExtensionPoint) for
+//! plugin-to-plugin messaging.
+//!
+//! Plugins that want to receive messages register a hook for [`MessageReceiver`]. Anyone with
+//! access to the [`PluginManager`](crate::PluginManager) can then send a message to all enabled
+//! receivers with [`PluginManager::publish`](crate::PluginManager::publish). Messages consist of
+//! a topic and a payload of bytes, which keeps the channel independent of any serialization
+//! format.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::Mutex;
+//! use steckrs::{simple_plugin, PluginManager, messaging::{MessageReceiver, MessageReceiverTrait}};
+//!
+//! static RECEIVED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+//!
+//! struct Inbox;
+//! impl MessageReceiverTrait for Inbox {
+//!     fn receive(&self, topic: &str, payload: &[u8]) {
+//!         if topic == "greeting" {
+//!             let text = String::from_utf8_lossy(payload).into_owned();
+//!             RECEIVED.lock().unwrap().push(text);
+//!         }
+//!     }
+//! }
+//!
+//! simple_plugin!(
+//!     InboxPlugin,
+//!     "inbox_plugin",
+//!     "Receives greetings",
+//!     hooks: [(MessageReceiver, Inbox)]
+//! );
+//!
+//! let mut manager = PluginManager::new();
+//! manager.load_plugin(Box::new(InboxPlugin::new())).unwrap();
+//! manager.enable_plugin(InboxPlugin::ID).unwrap();
+//!
+//! manager.publish("greeting", b"hello");
+//! manager.publish("unrelated", b"ignored");
+//!
+//! assert_eq!(*RECEIVED.lock().unwrap(), vec!["hello".to_string()]);
+//! ```
+
+use crate::extension_point;
+
+extension_point!(
+    /// Extension point for receiving messages sent with
+    /// [`PluginManager::publish`](crate::PluginManager::publish).
+    MessageReceiver:
+    /// Trait for hooks of the [`MessageReceiver`] extension point.
+    MessageReceiverTrait;
+    /// Called for every published message.
+    ///
+    /// Receivers get all messages and should ignore topics they are not interested in.
+    fn receive(&self, topic: &str, payload: &[u8]);
+);
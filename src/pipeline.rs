@@ -0,0 +1,211 @@
+//! # Pipelines
+//!
+//! This module provides [`Pipeline`], which threads a value through the hooks of several
+//! [extension points](crate::hook::ExtensionPoint) in a fixed order.
+//!
+//! Multi-stage processing, like parse → transform → render, maps naturally to one extension
+//! point per stage. Each stage has its own hook trait, so the stages can not be stored together
+//! directly. A [`Pipeline`] erases the type of each extension point behind the [`Stage`] trait,
+//! and only keeps the type of the value that is passed from stage to stage.
+//!
+//! Within a stage, the value is passed through the hooks of all enabled plugins, in the order of
+//! [`PluginManager::get_enabled_hooks_by_ep`]. The output of one hook is the input of the next,
+//! and the output of the last hook of a stage is the input of the next stage. A stage without
+//! hooks passes the value through unchanged.
+//!
+//! # Examples
+//!
+//! ```
+//! use steckrs::{extension_point, simple_plugin, PluginManager, pipeline::Pipeline};
+//!
+//! extension_point!(
+//!     Transform: TransformTrait;
+//!     fn transform(&self, text: String) -> String;
+//! );
+//!
+//! extension_point!(
+//!     Render: RenderTrait;
+//!     fn render(&self, text: String) -> String;
+//! );
+//!
+//! struct Shout;
+//! impl TransformTrait for Shout {
+//!     fn transform(&self, text: String) -> String {
+//!         text.to_uppercase()
+//!     }
+//! }
+//!
+//! struct Brackets;
+//! impl RenderTrait for Brackets {
+//!     fn render(&self, text: String) -> String {
+//!         format!("[{text}]")
+//!     }
+//! }
+//!
+//! simple_plugin!(
+//!     TextPlugin,
+//!     "text_plugin",
+//!     "Processes text",
+//!     hooks: [(Transform, Shout), (Render, Brackets)]
+//! );
+//!
+//! let mut manager = PluginManager::new();
+//! manager.load_plugin(Box::new(TextPlugin::new())).unwrap();
+//! manager.enable_plugin(TextPlugin::ID).unwrap();
+//!
+//! let pipeline = Pipeline::new()
+//!     .stage::<Transform>("transform", |hook, text| hook.transform(text))
+//!     .stage::<Render>("render", |hook, text| hook.render(text));
+//!
+//! assert_eq!(pipeline.stage_names(), ["transform", "render"]);
+//! assert_eq!(pipeline.run(&manager, "hello".to_string()), "[HELLO]");
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::hook::ExtensionPoint;
+use crate::PluginManager;
+
+/// A single stage of a [`Pipeline`].
+///
+/// Stages for extension points are created with [`Pipeline::stage`]. Implement this trait to add
+/// stages that do not call hooks, like validation between two stages, with
+/// [`Pipeline::custom_stage`].
+pub trait Stage<T> {
+    /// Returns the name of this stage.
+    fn name(&self) -> &str;
+
+    /// Runs this stage, turning its input into its output.
+    fn run(&self, manager: &PluginManager, value: T) -> T;
+}
+
+/// Function that passes the value of a [`Pipeline`] through a single hook.
+type ApplyFn<E, T> = Box<dyn Fn(&<E as ExtensionPoint>::HookTrait, T) -> T>;
+
+/// A [`Stage`] that passes the value through the hooks of the [`ExtensionPoint`] `E`.
+struct HookStage<E: ExtensionPoint, T> {
+    name: String,
+    apply: ApplyFn<E, T>,
+    marker: PhantomData<fn() -> E>,
+}
+
+impl<E: ExtensionPoint, T> Stage<T> for HookStage<E, T> {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&self, manager: &PluginManager, value: T) -> T {
+        manager
+            .get_enabled_hooks_by_ep::<E>()
+            .into_iter()
+            .fold(value, |value, (_id, hook)| {
+                (self.apply)(hook.inner(), value)
+            })
+    }
+}
+
+/// Threads a value through the hooks of several extension points, stage by stage.
+///
+/// See the [module documentation](self) for details.
+pub struct Pipeline<T> {
+    stages: Vec<Box<dyn Stage<T>>>,
+}
+
+impl<T: 'static> Pipeline<T> {
+    /// Creates a new pipeline without stages.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { stages: Vec::new() }
+    }
+
+    /// Adds a stage for the [`ExtensionPoint`] `E` after all existing stages.
+    ///
+    /// `apply` is called once per enabled hook of `E` and passes the value through that hook.
+    #[must_use]
+    pub fn stage<E: ExtensionPoint>(
+        self,
+        name: impl Into<String>,
+        apply: impl Fn(&E::HookTrait, T) -> T + 'static,
+    ) -> Self {
+        self.custom_stage(HookStage::<E, T> {
+            name: name.into(),
+            apply: Box::new(apply),
+            marker: PhantomData,
+        })
+    }
+
+    /// Adds a custom [`Stage`] after all existing stages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use steckrs::{PluginManager, pipeline::{Pipeline, Stage}};
+    ///
+    /// struct Clamp;
+    /// impl Stage<i64> for Clamp {
+    ///     fn name(&self) -> &str {
+    ///         "clamp"
+    ///     }
+    ///     fn run(&self, _manager: &PluginManager, value: i64) -> i64 {
+    ///         value.clamp(0, 100)
+    ///     }
+    /// }
+    ///
+    /// let pipeline = Pipeline::new().custom_stage(Clamp);
+    /// assert_eq!(pipeline.run(&PluginManager::new(), 250), 100);
+    /// ```
+    #[must_use]
+    pub fn custom_stage(mut self, stage: impl Stage<T> + 'static) -> Self {
+        self.stages.push(Box::new(stage));
+        self
+    }
+
+    /// Returns the names of all stages, in order.
+    #[must_use]
+    pub fn stage_names(&self) -> Vec<&str> {
+        self.stages.iter().map(|stage| stage.name()).collect()
+    }
+
+    /// Returns the number of stages.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Returns `true` if the pipeline has no stages.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    /// Runs all stages in order, starting with `input`, and returns the output of the last stage.
+    ///
+    /// Only hooks of enabled plugins of `manager` are called. A pipeline without stages returns
+    /// `input` unchanged.
+    pub fn run(&self, manager: &PluginManager, input: T) -> T {
+        self.stages
+            .iter()
+            .fold(input, |value, stage| stage.run(manager, value))
+    }
+}
+
+impl<T: 'static> Default for Pipeline<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for Pipeline<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pipeline")
+            .field(
+                "stages",
+                &self
+                    .stages
+                    .iter()
+                    .map(|stage| stage.name())
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
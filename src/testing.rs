@@ -0,0 +1,111 @@
+//! # Testing
+//!
+//! This module provides helpers for testing [Plugins](crate::Plugin) against a real
+//! [`PluginManager`].
+//!
+//! ## Overview
+//!
+//! - [`PluginHarness`]: Loads and enables a single plugin, and gives access to its hooks
+//!
+//! Testing a plugin with the harness exercises the same code paths as a host application would,
+//! including [hook registration](crate::Plugin::register_hooks) and the lifecycle methods.
+
+use crate::error::PluginResult;
+use crate::hook::{ExtensionPoint, Hook, HookID};
+use crate::{Plugin, PluginID, PluginManager};
+
+/// A [`PluginManager`] with a single loaded and enabled [`Plugin`], for testing that plugin.
+///
+/// # Examples
+///
+/// ```
+/// use steckrs::{extension_point, simple_plugin, testing::PluginHarness};
+///
+/// extension_point!(
+///     Greeter: GreeterTrait;
+///     fn greet(&self, name: &str) -> String;
+/// );
+///
+/// struct EnglishGreeter;
+/// impl GreeterTrait for EnglishGreeter {
+///     fn greet(&self, name: &str) -> String {
+///         format!("Hello, {}!", name)
+///     }
+/// }
+///
+/// simple_plugin!(
+///     HelloPlugin,
+///     "hello_plugin",
+///     "A simple greeting plugin",
+///     hooks: [(Greeter, EnglishGreeter)]
+/// );
+///
+/// let harness = PluginHarness::new(Box::new(HelloPlugin::new())).unwrap();
+///
+/// let hooks = harness.hooks::<Greeter>();
+/// assert_eq!(hooks.len(), 1);
+/// assert_eq!(hooks[0].1.inner().greet("World"), "Hello, World!");
+/// ```
+#[derive(Debug)]
+pub struct PluginHarness {
+    manager: PluginManager,
+    plugin_id: PluginID,
+}
+
+impl PluginHarness {
+    /// Creates a new [`PluginManager`], then loads and enables the given plugin in it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PluginError`](crate::error::PluginError) if loading or enabling the plugin
+    /// fails, see [`PluginManager::load_plugin`].
+    pub fn new(plugin: Box<dyn Plugin>) -> PluginResult<Self> {
+        let plugin_id = plugin.id();
+        let mut manager = PluginManager::new();
+        manager.load_plugin(plugin)?;
+        manager.enable_plugin(plugin_id)?;
+        Ok(Self { manager, plugin_id })
+    }
+
+    /// Returns the ID of the plugin under test.
+    #[must_use]
+    pub fn plugin_id(&self) -> PluginID {
+        self.plugin_id
+    }
+
+    /// Returns a reference to the plugin under test.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the plugin was unloaded through [`manager_mut`](Self::manager_mut).
+    #[must_use]
+    pub fn plugin(&self) -> &dyn Plugin {
+        self.manager
+            .get_plugin(self.plugin_id)
+            .expect("the plugin under test was unloaded")
+    }
+
+    /// Gets the hooks of the plugin under test for a specific [`ExtensionPoint`] type.
+    ///
+    /// This is the same as [`PluginManager::get_enabled_hooks_by_ep`], so the hooks disappear
+    /// if the plugin is disabled through [`manager_mut`](Self::manager_mut).
+    #[must_use]
+    pub fn hooks<E: ExtensionPoint>(&self) -> Vec<(&HookID, &Hook<E>)> {
+        self.manager.get_enabled_hooks_by_ep::<E>()
+    }
+
+    /// Returns a reference to the underlying [`PluginManager`].
+    #[must_use]
+    pub fn manager(&self) -> &PluginManager {
+        &self.manager
+    }
+
+    /// Returns a mutable reference to the underlying [`PluginManager`].
+    ///
+    /// Use this to test how the plugin behaves when it is disabled, or together with other
+    /// plugins.
+    #[must_use]
+    pub fn manager_mut(&mut self) -> &mut PluginManager {
+        &mut self.manager
+    }
+}
@@ -0,0 +1,115 @@
+//! # Utilities
+//!
+//! This module provides helpers for writing hooks.
+//!
+//! ## Overview
+//!
+//! - [`CachingHook`]: Memoizes the results of a pure hook by its input
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Mutex, PoisonError};
+
+/// Wraps a hook and memoizes its results by input.
+///
+/// This is meant for pure hooks, where the same input always gives the same output, and computing
+/// the output is expensive. Implement the [`HookTrait`](crate::hook::ExtensionPoint::HookTrait)
+/// for `CachingHook<YourHook, In, Out>` and compute the results with
+/// [`get_or_compute`](Self::get_or_compute). The cache is behind a [`Mutex`], since hooks are
+/// only accessed through `&self` and must be [`Sync`].
+///
+/// Results are never evicted, so the cache grows with the number of distinct inputs. Use
+/// [`clear`](Self::clear) if that becomes a problem. If the hook is not pure, the cache returns
+/// outdated results.
+///
+/// # Examples
+///
+/// ```
+/// use steckrs::{extension_point, hook::{Hook, HookID, HookRegistry}, util::CachingHook};
+///
+/// extension_point!(
+///     Fibonacci: FibonacciTrait;
+///     fn fib(&self, n: u64) -> u64;
+/// );
+///
+/// struct NaiveFibonacci;
+/// impl NaiveFibonacci {
+///     fn compute(&self, n: u64) -> u64 {
+///         if n < 2 { n } else { self.compute(n - 1) + self.compute(n - 2) }
+///     }
+/// }
+///
+/// impl FibonacciTrait for CachingHook<NaiveFibonacci, u64, u64> {
+///     fn fib(&self, n: u64) -> u64 {
+///         self.get_or_compute(n, |hook, n| hook.compute(*n))
+///     }
+/// }
+///
+/// let mut registry = HookRegistry::new();
+/// let id = HookID::for_ep::<Fibonacci>("math_plugin", None);
+/// let hook = CachingHook::new(NaiveFibonacci);
+/// registry.register(&id, Hook::<Fibonacci>::new(Box::new(hook), "fib")).unwrap();
+///
+/// let hooks = registry.get_by_extension_point::<Fibonacci>();
+/// assert_eq!(hooks[0].1.inner().fib(25), 75025);
+/// assert_eq!(hooks[0].1.inner().fib(25), 75025); // from the cache
+/// ```
+#[derive(Debug)]
+pub struct CachingHook<H, In, Out> {
+    inner: H,
+    cache: Mutex<HashMap<In, Out>>,
+}
+
+impl<H, In, Out> CachingHook<H, In, Out>
+where
+    In: Eq + Hash,
+    Out: Clone,
+{
+    /// Wraps a hook with an empty cache.
+    #[must_use]
+    pub fn new(inner: H) -> Self {
+        Self {
+            inner,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the cached result for `input`, or computes it with `compute` and caches it.
+    ///
+    /// The cache is not locked while `compute` runs, so `compute` may call other methods of the
+    /// hook. If the same input is computed concurrently, both computations run, and the result
+    /// of the last one is kept.
+    pub fn get_or_compute(&self, input: In, compute: impl FnOnce(&H, &In) -> Out) -> Out {
+        if let Some(cached) = self.lock().get(&input) {
+            return cached.clone();
+        }
+        let output = compute(&self.inner, &input);
+        self.lock().insert(input, output.clone());
+        output
+    }
+
+    /// Returns a reference to the wrapped hook.
+    #[must_use]
+    pub fn inner(&self) -> &H {
+        &self.inner
+    }
+
+    /// Returns the number of cached results.
+    #[must_use]
+    pub fn cached(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Removes all cached results.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Internal helper that locks the cache, ignoring poisoning.
+    ///
+    /// A panic in another thread cannot leave the cache half-updated, since every result is
+    /// inserted at once.
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<In, Out>> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}